@@ -0,0 +1,93 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+/// Soulbound badge collection: the DAO mints badges, nobody can transfer them.
+#[openbrush::contract]
+pub mod badge {
+    use openbrush::contracts::ownable::*;
+    use openbrush::contracts::psp34::extensions::mintable::*;
+    use openbrush::modifiers;
+    use openbrush::traits::{
+        Storage,
+        String,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct BadgeContract {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+    }
+
+    impl BadgeContract {
+        /// `minter` is the DAO the badges are minted by.
+        #[ink(constructor)]
+        pub fn new(minter: AccountId) -> Self {
+            let mut instance = Self::default();
+            instance._init_with_owner(minter);
+            instance
+        }
+    }
+
+    impl PSP34 for BadgeContract {}
+
+    impl Ownable for BadgeContract {}
+
+    impl PSP34Mintable for BadgeContract {
+        #[modifiers(only_owner)]
+        fn mint(&mut self, account: AccountId, id: Id) -> Result<(), PSP34Error> {
+            self._mint_to(account, id)
+        }
+    }
+
+    impl psp34::Internal for BadgeContract {
+        fn _before_token_transfer(
+            &mut self,
+            from: Option<&AccountId>,
+            to: Option<&AccountId>,
+            _id: &Id,
+        ) -> Result<(), PSP34Error> {
+            // Badges can be minted and burned but never change hands
+            if from.is_some() && to.is_some() {
+                return Err(PSP34Error::Custom(String::from("Soulbound")))
+            }
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::badge::BadgeContract;
+        use ink::env::test;
+        use ink::prelude::vec::Vec;
+
+        #[ink::test]
+        fn badges_are_soulbound() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+            let mut badge = BadgeContract::new(accounts.alice);
+
+            // Only the DAO mints
+            set_sender(accounts.bob);
+            assert!(badge.mint(accounts.bob,Id::U32(1)).is_err());
+            set_sender(accounts.alice);
+            assert!(badge.mint(accounts.bob,Id::U32(1)).is_ok());
+            assert_eq!(badge.owner_of(Id::U32(1)),Some(accounts.bob));
+
+            set_sender(accounts.bob);
+            assert_eq!(badge.transfer(accounts.charlie,Id::U32(1),Vec::new()),Err(PSP34Error::Custom(String::from("Soulbound"))));
+            assert_eq!(badge.owner_of(Id::U32(1)),Some(accounts.bob));
+        }
+
+        fn default_accounts() -> test::DefaultAccounts<ink::env::DefaultEnvironment> {
+            test::default_accounts::<Environment>()
+        }
+
+        fn set_sender(sender: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(sender);
+        }
+    }
+}
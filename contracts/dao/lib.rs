@@ -5,7 +5,6 @@
 pub mod dao {
     use ink::prelude::vec;
     use ink::prelude::vec::Vec;
-    use openbrush::contracts::ownable::*;
     use openbrush::contracts::reentrancy_guard::*;
     use openbrush::traits::Storage;
     use ink::{
@@ -19,16 +18,36 @@ pub mod dao {
     
     use toyota_pkg::{
         impls::dao::*,
+        traits::badges::*,
+        traits::certificates::*,
         traits::dao::*,
+        traits::decay::*,
+        traits::epochs::*,
+        traits::invite::*,
+        traits::kudos::*,
+        traits::leaderboard::*,
+        traits::membership::*,
+        traits::multisig::*,
+        traits::points_token::*,
+        traits::profile::*,
+        traits::rewards::*,
+        traits::roles::*,
+        traits::seasons::*,
+        traits::streams::*,
+        traits::tiers::*,
+        traits::treasury::*,
+        traits::vouching::*,
     };
     use toyota_pkg::impls::dao::dao::DaoEvents;
+    use toyota_pkg::impls::eligibility::types::{
+        Credential,
+        EligibilityPolicy,
+    };
 
 
     #[ink(storage)]
     #[derive(Default, Storage)]
     pub struct DaoContract {
-        #[storage_field]
-        ownable: ownable::Data,
         #[storage_field]
         guard: reentrancy_guard::Data,
         #[storage_field]
@@ -43,6 +62,35 @@ pub mod dao {
         member_id: u32,
     }
 
+    #[ink(event)]
+    pub struct MemberRemoved {
+        #[ink(topic)]
+        member: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct VouchCast {
+        #[ink(topic)]
+        voucher: AccountId,
+        #[ink(topic)]
+        candidate: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct MemberInvited {
+        #[ink(topic)]
+        inviter: AccountId,
+        #[ink(topic)]
+        member: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct MembershipRenewed {
+        #[ink(topic)]
+        member: AccountId,
+        expiry: Timestamp,
+    }
+
     #[ink(event)]
     pub struct ProjectCreated {
         #[ink(topic)]
@@ -67,18 +115,223 @@ pub mod dao {
         task_id: u32,
     }
 
+    #[ink(event)]
+    pub struct ActionSubmitted {
+        #[ink(topic)]
+        signer: AccountId,
+        #[ink(topic)]
+        action_id: u32,
+    }
+
+    #[ink(event)]
+    pub struct ActionConfirmed {
+        #[ink(topic)]
+        signer: AccountId,
+        #[ink(topic)]
+        action_id: u32,
+    }
+
+    #[ink(event)]
+    pub struct ActionExecuted {
+        #[ink(topic)]
+        signer: AccountId,
+        #[ink(topic)]
+        action_id: u32,
+    }
+
+    #[ink(event)]
+    pub struct RoleGranted {
+        #[ink(topic)]
+        role: u32,
+        #[ink(topic)]
+        account: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct RoleRevoked {
+        #[ink(topic)]
+        role: u32,
+        #[ink(topic)]
+        account: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct TierChanged {
+        #[ink(topic)]
+        member: AccountId,
+        tier: u32,
+    }
+
+    #[ink(event)]
+    pub struct ProfileUpdated {
+        #[ink(topic)]
+        member: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct KudosGiven {
+        #[ink(topic)]
+        sender: AccountId,
+        #[ink(topic)]
+        recipient: AccountId,
+        points: u32,
+        message_hash: Hash,
+    }
+
+    #[ink(event)]
+    pub struct PointsTransferred {
+        #[ink(topic)]
+        sender: AccountId,
+        #[ink(topic)]
+        recipient: AccountId,
+        points: u32,
+    }
+
+    #[ink(event)]
+    pub struct EpochStarted {
+        #[ink(topic)]
+        epoch_id: u32,
+    }
+
+    #[ink(event)]
+    pub struct EpochClosed {
+        #[ink(topic)]
+        epoch_id: u32,
+        total_allocated: u32,
+    }
+
+    #[ink(event)]
+    pub struct SeasonClosed {
+        #[ink(topic)]
+        season_id: u32,
+    }
+
+    #[ink(event)]
+    pub struct BadgeMinted {
+        #[ink(topic)]
+        member: AccountId,
+        #[ink(topic)]
+        badge_id: u32,
+        token_id: types::TokenId,
+    }
+
+    #[ink(event)]
+    pub struct CertificateMinted {
+        #[ink(topic)]
+        member: AccountId,
+        #[ink(topic)]
+        task_id: u32,
+        token_id: types::TokenId,
+    }
+
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        value: Balance,
+    }
+
+    #[ink(event)]
+    pub struct PointsRedeemed {
+        #[ink(topic)]
+        member: AccountId,
+        #[ink(topic)]
+        redemption_id: u32,
+        points: u32,
+        reward: Balance,
+    }
+
+    #[ink(event)]
+    pub struct RewardsClaimed {
+        #[ink(topic)]
+        member: AccountId,
+        #[ink(topic)]
+        redemption_id: u32,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Deposit {
+        #[ink(topic)]
+        from: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct TreasurySpent {
+        #[ink(topic)]
+        spend_id: u32,
+        asset: types::Asset,
+        #[ink(topic)]
+        beneficiary: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct TokensRecovered {
+        #[ink(topic)]
+        token: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct StreamCreated {
+        #[ink(topic)]
+        stream_id: u32,
+        #[ink(topic)]
+        beneficiary: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct StreamWithdrawn {
+        #[ink(topic)]
+        stream_id: u32,
+        #[ink(topic)]
+        beneficiary: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct StreamCancelled {
+        #[ink(topic)]
+        stream_id: u32,
+        settled: Balance,
+    }
+
+    #[ink(event)]
+    pub struct EpochPayoutClaimed {
+        #[ink(topic)]
+        member: AccountId,
+        #[ink(topic)]
+        epoch_id: u32,
+        amount: Balance,
+    }
+
     impl DaoContract {
         #[ink(constructor)]
-        pub fn new(token: AccountId, metadata: Vec<u8>) -> Self {
-            
+        pub fn new(token: AccountId, metadata: Vec<u8>, signers: Vec<AccountId>, threshold: u32) -> Self {
+                assert!(
+                    multisig::valid_threshold(threshold,signers.len() as u32),
+                    "invalid multisig threshold"
+                );
+                for (i, signer) in signers.iter().enumerate() {
+                    assert!(!signers[..i].contains(signer), "duplicate multisig signer");
+                }
                 let mut instance = Self::default();
                 instance.dao.token = token;
+                instance.dao.eligibility = EligibilityPolicy::Psp34(vec![token]);
                 instance.dao.quorum = 0; // 0%
                 instance.dao.metadata = metadata;
                 let caller = instance.env().caller();
-                instance._init_with_owner(caller.clone());
                 instance.dao.members = vec![caller];
                 instance.dao.member_id = 1;
+                instance.dao.last_activity.insert(&caller,&instance.env().block_timestamp());
+                instance.dao.signers = signers;
+                instance.dao.threshold = threshold;
                 instance
         }
         
@@ -97,6 +350,53 @@ pub mod dao {
         );
         }
 
+        fn emit_member_removed_event(&self, member:AccountId) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            MemberRemoved,
+        >(
+            self.env(),
+            MemberRemoved {
+                member,
+            },
+        );
+        }
+
+        fn emit_vouch_cast_event(&self, voucher:AccountId, candidate:AccountId) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            VouchCast,
+        >(
+            self.env(),
+            VouchCast {
+                voucher,
+                candidate,
+            },
+        );
+        }
+
+        fn emit_member_invited_event(&self, inviter:AccountId, member:AccountId) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            MemberInvited,
+        >(
+            self.env(),
+            MemberInvited {
+                inviter,
+                member,
+            },
+        );
+        }
+
+        fn emit_membership_renewed_event(&self, member:AccountId, expiry: Timestamp) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            MembershipRenewed,
+        >(
+            self.env(),
+            MembershipRenewed {
+                member,
+                expiry,
+            },
+        );
+        }
+
         fn emit_project_created_event(&self, creator:AccountId, project_id: u32) {
             <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
             ProjectCreated,
@@ -132,51 +432,406 @@ pub mod dao {
             },
         );
         }
-    }
 
-    impl ToyotaDao for DaoContract {}
+        fn emit_action_submitted_event(&self, signer:AccountId, action_id: u32) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            ActionSubmitted,
+        >(
+            self.env(),
+            ActionSubmitted {
+                signer,
+                action_id,
+            },
+        );
+        }
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
-        use crate::dao::DaoContract;
-        use ink::env::test;
-        use openbrush::{
-            traits::String,
-        };
+        fn emit_action_confirmed_event(&self, signer:AccountId, action_id: u32) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            ActionConfirmed,
+        >(
+            self.env(),
+            ActionConfirmed {
+                signer,
+                action_id,
+            },
+        );
+        }
 
-        #[ink::test]
-        fn new_works() {
-            let accounts = default_accounts();
-            set_sender(accounts.alice);
-            let dao = init_contract();
-            
-            assert_eq!(dao.get_token_address(), token_address());
-            assert_eq!(dao.get_number_of_members(),1u32);
+        fn emit_action_executed_event(&self, signer:AccountId, action_id: u32) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            ActionExecuted,
+        >(
+            self.env(),
+            ActionExecuted {
+                signer,
+                action_id,
+            },
+        );
         }
 
-        #[ink::test]
-        fn add_member_works() {
-            let accounts = default_accounts();
-            set_sender(accounts.alice);
+        fn emit_role_granted_event(&self, role: u32, account:AccountId) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            RoleGranted,
+        >(
+            self.env(),
+            RoleGranted {
+                role,
+                account,
+            },
+        );
+        }
 
-            let mut dao = init_contract();
-            assert_eq!(dao.get_number_of_members(),1u32);
+        fn emit_role_revoked_event(&self, role: u32, account:AccountId) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            RoleRevoked,
+        >(
+            self.env(),
+            RoleRevoked {
+                role,
+                account,
+            },
+        );
+        }
 
-            assert!(dao.add_member(accounts.bob).is_ok());
-            assert_eq!(dao.get_number_of_members(),2u32);
+        fn emit_tier_changed_event(&self, member:AccountId, tier: u32) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            TierChanged,
+        >(
+            self.env(),
+            TierChanged {
+                member,
+                tier,
+            },
+        );
+        }
 
-            assert_eq!(dao.get_members(),vec![accounts.alice,accounts.bob]);
+        fn emit_profile_updated_event(&self, member:AccountId) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            ProfileUpdated,
+        >(
+            self.env(),
+            ProfileUpdated {
+                member,
+            },
+        );
         }
 
-        #[ink::test]
-        fn create_project_works() {
-            let accounts = default_accounts();
-            set_sender(accounts.alice);
+        fn emit_kudos_given_event(&self, sender:AccountId, recipient:AccountId, points: u32, message_hash: Hash) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            KudosGiven,
+        >(
+            self.env(),
+            KudosGiven {
+                sender,
+                recipient,
+                points,
+                message_hash,
+            },
+        );
+        }
 
-            let mut dao = init_contract();
+        fn emit_points_transferred_event(&self, sender:AccountId, recipient:AccountId, points: u32) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            PointsTransferred,
+        >(
+            self.env(),
+            PointsTransferred {
+                sender,
+                recipient,
+                points,
+            },
+        );
+        }
+
+        fn emit_epoch_started_event(&self, epoch_id: u32) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            EpochStarted,
+        >(
+            self.env(),
+            EpochStarted {
+                epoch_id,
+            },
+        );
+        }
+
+        fn emit_epoch_closed_event(&self, epoch_id: u32, total_allocated: u32) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            EpochClosed,
+        >(
+            self.env(),
+            EpochClosed {
+                epoch_id,
+                total_allocated,
+            },
+        );
+        }
+
+        fn emit_season_closed_event(&self, season_id: u32) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            SeasonClosed,
+        >(
+            self.env(),
+            SeasonClosed {
+                season_id,
+            },
+        );
+        }
+
+        fn emit_badge_minted_event(&self, member:AccountId, badge_id: u32, token_id: types::TokenId) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            BadgeMinted,
+        >(
+            self.env(),
+            BadgeMinted {
+                member,
+                badge_id,
+                token_id,
+            },
+        );
+        }
+
+        fn emit_certificate_minted_event(&self, member:AccountId, task_id: u32, token_id: types::TokenId) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            CertificateMinted,
+        >(
+            self.env(),
+            CertificateMinted {
+                member,
+                task_id,
+                token_id,
+            },
+        );
+        }
+
+        fn emit_points_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, value: Balance) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            Transfer,
+        >(
+            self.env(),
+            Transfer {
+                from,
+                to,
+                value,
+            },
+        );
+        }
+
+        fn emit_points_redeemed_event(&self, member: AccountId, redemption_id: u32, points: u32, reward: Balance) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            PointsRedeemed,
+        >(
+            self.env(),
+            PointsRedeemed {
+                member,
+                redemption_id,
+                points,
+                reward,
+            },
+        );
+        }
+
+        fn emit_rewards_claimed_event(&self, member: AccountId, redemption_id: u32, amount: Balance) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            RewardsClaimed,
+        >(
+            self.env(),
+            RewardsClaimed {
+                member,
+                redemption_id,
+                amount,
+            },
+        );
+        }
+
+        fn emit_deposit_event(&self, from: AccountId, amount: Balance) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            Deposit,
+        >(
+            self.env(),
+            Deposit {
+                from,
+                amount,
+            },
+        );
+        }
+
+        fn emit_treasury_spent_event(&self, spend_id: u32, asset: types::Asset, beneficiary: AccountId, amount: Balance) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            TreasurySpent,
+        >(
+            self.env(),
+            TreasurySpent {
+                spend_id,
+                asset,
+                beneficiary,
+                amount,
+            },
+        );
+        }
+
+        fn emit_tokens_recovered_event(&self, token: AccountId, to: AccountId, amount: Balance) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            TokensRecovered,
+        >(
+            self.env(),
+            TokensRecovered {
+                token,
+                to,
+                amount,
+            },
+        );
+        }
+
+        fn emit_stream_created_event(&self, stream_id: u32, beneficiary: AccountId) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            StreamCreated,
+        >(
+            self.env(),
+            StreamCreated {
+                stream_id,
+                beneficiary,
+            },
+        );
+        }
+
+        fn emit_stream_withdrawn_event(&self, stream_id: u32, beneficiary: AccountId, amount: Balance) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            StreamWithdrawn,
+        >(
+            self.env(),
+            StreamWithdrawn {
+                stream_id,
+                beneficiary,
+                amount,
+            },
+        );
+        }
+
+        fn emit_stream_cancelled_event(&self, stream_id: u32, settled: Balance) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            StreamCancelled,
+        >(
+            self.env(),
+            StreamCancelled {
+                stream_id,
+                settled,
+            },
+        );
+        }
+
+        fn emit_epoch_payout_claimed_event(&self, member: AccountId, epoch_id: u32, amount: Balance) {
+            <EnvAccess<'_, DefaultEnvironment> as EmitEvent<DaoContract>>::emit_event::<
+            EpochPayoutClaimed,
+        >(
+            self.env(),
+            EpochPayoutClaimed {
+                member,
+                epoch_id,
+                amount,
+            },
+        );
+        }
+    }
+
+    impl ToyotaDao for DaoContract {}
+
+    impl DaoBadges for DaoContract {}
+
+    impl DaoCertificates for DaoContract {}
+
+    impl DaoDecay for DaoContract {}
+
+    impl DaoEpochs for DaoContract {}
+
+    impl DaoInvite for DaoContract {}
+
+    impl DaoKudos for DaoContract {}
+
+    impl DaoLeaderboard for DaoContract {}
+
+    impl DaoMembership for DaoContract {}
+
+    impl DaoMultisig for DaoContract {}
+
+    impl PSP22 for DaoContract {}
+
+    impl DaoProfile for DaoContract {}
+
+    impl DaoRewards for DaoContract {}
+
+    impl DaoRoles for DaoContract {}
+
+    impl DaoSeasons for DaoContract {}
+
+    impl DaoStreams for DaoContract {}
+
+    impl DaoTiers for DaoContract {}
+
+    impl DaoTreasury for DaoContract {}
+
+    impl DaoVouching for DaoContract {}
+
+    // Cross-contract calls are not available off-chain, so in tests the
+    // collections accept every mint.
+    #[cfg(test)]
+    impl certificates::CertificatesInternal for DaoContract {
+        fn mint_certificate_token(&self,_contract: AccountId,_to: AccountId,_token_id: types::TokenId,_attributes: Vec<(Vec<u8>,Vec<u8>)>) -> bool {
+            true
+        }
+    }
+
+    #[cfg(test)]
+    impl badges::BadgesInternal for DaoContract {
+        fn mint_badge_token(&self,_contract: AccountId,_to: AccountId,_token_id: types::TokenId) -> bool {
+            true
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::dao::DaoContract;
+        use ink::env::test;
+        use openbrush::{
+            contracts::psp22::PSP22Error,
+            contracts::psp34::Id,
+            traits::String,
+        };
+
+        #[ink::test]
+        fn new_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+            let dao = init_contract();
+            
+            assert_eq!(dao.get_token_address(), token_address());
+            assert_eq!(dao.get_number_of_members(),1u32);
+        }
+
+        #[ink::test]
+        fn add_member_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert_eq!(dao.get_number_of_members(),1u32);
+
+            assert!(dao.add_member(accounts.bob).is_ok());
+            assert_eq!(dao.get_number_of_members(),2u32);
+
+            assert_eq!(dao.get_members(),vec![accounts.alice,accounts.bob]);
+        }
+
+        #[ink::test]
+        fn create_project_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
             assert!(dao.add_member(accounts.bob).is_ok());
             assert!(dao.add_member(accounts.charlie).is_ok());
+            grant_role(&mut dao,types::PROJECT_LEAD,accounts.bob);
+            grant_role(&mut dao,types::PROJECT_LEAD,accounts.charlie);
 
             // Bob creates a new project
             set_sender(accounts.bob);
@@ -198,6 +853,7 @@ pub mod dao {
             let mut dao = init_contract();
             assert!(dao.add_member(accounts.bob).is_ok());
             assert!(dao.add_member(accounts.charlie).is_ok());
+            grant_role(&mut dao,types::PROJECT_LEAD,accounts.bob);
 
             // Bob creates a new project
             set_sender(accounts.bob);
@@ -235,6 +891,7 @@ pub mod dao {
             let mut dao = init_contract();
             assert!(dao.add_member(accounts.bob).is_ok());
             assert!(dao.add_member(accounts.charlie).is_ok());
+            grant_role(&mut dao,types::PROJECT_LEAD,accounts.bob);
 
             // Bob creates a new project
             set_sender(accounts.bob);
@@ -299,17 +956,1412 @@ pub mod dao {
 
         }
 
-        fn default_accounts() -> test::DefaultAccounts<ink::env::DefaultEnvironment> {
-            test::default_accounts::<Environment>()
+        #[ink::test]
+        fn multisig_add_member_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            let actions = dao.get_number_of_actions();
+            assert_eq!(dao.get_signers(),vec![accounts.alice]);
+
+            // Adding Bob as a second signer raises the threshold to 2
+            assert!(dao.submit_action(types::AdminAction::AddSigner(accounts.bob)).is_ok());
+            assert!(dao.execute_action(actions + 1).is_ok());
+            assert_eq!(dao.get_threshold(),2u32);
+
+            // A threshold of 1 is not allowed with several signers
+            assert!(dao.submit_action(types::AdminAction::ChangeThreshold(1)).is_ok());
+            set_sender(accounts.bob);
+            assert!(dao.confirm_action(actions + 2).is_ok());
+            assert_eq!(dao.execute_action(actions + 2),Err(types::DaoError::InvalidThreshold));
+
+            // Adding Charlie now needs both signers
+            set_sender(accounts.alice);
+            assert!(dao.submit_action(types::AdminAction::AddMember(accounts.charlie)).is_ok());
+            assert_eq!(dao.execute_action(actions + 3),Err(types::DaoError::NotEnoughConfirmations));
+
+            set_sender(accounts.bob);
+            assert!(dao.confirm_action(actions + 3).is_ok());
+            assert!(dao.execute_action(actions + 3).is_ok());
+            assert_eq!(dao.get_members(),vec![accounts.alice,accounts.charlie]);
+
+            // Executed actions cannot be replayed
+            assert_eq!(dao.execute_action(actions + 3),Err(types::DaoError::ActionNotPending));
+
+            // Charlie is a member but not a signer
+            set_sender(accounts.charlie);
+            assert_eq!(dao.submit_action(types::AdminAction::SetQuorum(1)),Err(types::DaoError::NotASigner));
         }
 
-        fn set_sender(sender: AccountId) {
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(sender);
+        #[ink::test]
+        fn new_with_signers_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let dao = DaoContract::new(token_address(),String::from("Test"),vec![accounts.bob,accounts.charlie],2);
+            assert_eq!(dao.get_signers(),vec![accounts.bob,accounts.charlie]);
+            assert_eq!(dao.get_threshold(),2u32);
+            assert!(!dao.get_signers().contains(&accounts.alice));
+
+            // The deployer gets no roles, they are granted through governance or the multisig
+            assert!(dao.get_roles(accounts.alice).is_empty());
+            for role in types::ROLES.iter() {
+                assert!(dao.get_role_members(*role).is_empty());
+            }
         }
 
-        fn init_contract() -> DaoContract {
-            let metadata = String::from("Test");
-            DaoContract::new(token_address(),metadata)
+        #[ink::test]
+        #[should_panic(expected = "invalid multisig threshold")]
+        fn new_rejects_single_confirmation_for_several_signers() {
+            let accounts = default_accounts();
+            DaoContract::new(token_address(),String::from("Test"),vec![accounts.alice,accounts.bob],1);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "duplicate multisig signer")]
+        fn new_rejects_duplicate_signers() {
+            let accounts = default_accounts();
+            DaoContract::new(token_address(),String::from("Test"),vec![accounts.alice,accounts.alice],1);
+        }
+
+        #[ink::test]
+        fn multisig_veto_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            let actions = dao.get_number_of_actions();
+            assert!(dao.add_member(accounts.bob).is_ok());
+
+            set_sender(accounts.bob);
+            assert!(dao.create_proposal(String::from("Proposal 1"),100000).is_ok());
+
+            set_sender(accounts.alice);
+            assert!(dao.submit_action(types::AdminAction::VetoProposal(1)).is_ok());
+            assert!(dao.execute_action(actions + 1).is_ok());
+            assert_eq!(dao.get_proposal_vote(1).vote_status,types::VoteStatus::Vetoed);
+
+            set_sender(accounts.bob);
+            assert_eq!(dao.vote(1,true),Err(types::DaoError::VoteNotAvailable));
+        }
+
+        #[ink::test]
+        fn apply_for_membership_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+
+            // Django does not hold the NFT and applies instead
+            set_sender(accounts.django);
+            assert!(dao.apply_for_membership(String::from("Contractor for the UI")).is_ok());
+            assert_eq!(dao.get_membership_application(accounts.django),Some(1));
+            assert_eq!(dao.apply_for_membership(String::from("Again")),Err(types::DaoError::ApplicationAlreadyExists));
+
+            set_sender(accounts.alice);
+            assert!(dao.vote(1,true).is_ok());
+            assert_eq!(dao.vote(1,true),Err(types::DaoError::MemberHasAlreadyVoted));
+            set_sender(accounts.bob);
+            assert!(dao.vote(1,true).is_ok());
+
+            set_block_timestamp(dao.get_voting_period() + 1);
+            assert!(dao.finalize_vote(1).is_ok());
+
+            assert_eq!(dao.get_proposal_vote(1).vote_status,types::VoteStatus::Passed);
+            assert_eq!(dao.get_members(),vec![accounts.alice,accounts.bob,accounts.django]);
+            assert_eq!(dao.get_membership_application(accounts.django),None);
+        }
+
+        #[ink::test]
+        fn leave_dao_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+            assert!(dao.add_member(accounts.charlie).is_ok());
+            grant_role(&mut dao,types::PROJECT_LEAD,accounts.bob);
+            grant_role(&mut dao,types::REVIEWER,accounts.charlie);
+
+            // Bob joins a project and gets a task from Alice
+            set_sender(accounts.bob);
+            assert!(dao.create_project(String::from("Project 1")).is_ok());
+            assert!(dao.join_project(1).is_ok());
+            set_sender(accounts.alice);
+            assert!(dao.create_task(String::from("Task 1"),accounts.bob,accounts.charlie,1000000,100,1).is_ok());
+
+            set_sender(accounts.bob);
+            assert!(dao.leave_dao().is_ok());
+            assert_eq!(dao.get_members(),vec![accounts.alice,accounts.charlie]);
+            assert_eq!(dao.get_number_of_members(),2u32);
+            assert_eq!(dao.get_project_members(1),Vec::<AccountId>::new());
+
+            // The open task goes back to Alice who created it
+            assert_eq!(dao.get_task(1).assignee,accounts.alice);
+            assert_eq!(dao.get_member_task_ids(accounts.alice),vec![1]);
+            assert_eq!(dao.get_member_task_ids(accounts.bob),Vec::<u32>::new());
+            assert!(!dao.has_role(types::PROJECT_LEAD,accounts.bob));
+
+            assert_eq!(dao.leave_dao(),Err(types::DaoError::MemberDoesNotExist));
+
+            // Members added later still get a fresh id
+            set_sender(accounts.alice);
+            assert!(dao.add_member(accounts.django).is_ok());
+            assert_eq!(dao.get_number_of_members(),3u32);
+        }
+
+        #[ink::test]
+        fn orphaned_tasks_can_be_reassigned() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+            assert!(dao.add_member(accounts.charlie).is_ok());
+            assert!(dao.add_member(accounts.django).is_ok());
+            grant_role(&mut dao,types::REVIEWER,accounts.django);
+
+            assert!(dao.create_task(String::from("Task 1"),accounts.bob,accounts.alice,1000000,10,1).is_ok());
+            assert!(dao.create_task(String::from("Task 2"),accounts.alice,accounts.django,1000000,10,1).is_ok());
+
+            // Alice cannot take over tasks she would have to review herself, so both are orphaned
+            set_sender(accounts.bob);
+            assert!(dao.leave_dao().is_ok());
+            set_sender(accounts.django);
+            assert!(dao.leave_dao().is_ok());
+            assert_eq!(dao.get_task(1).assignee,AccountId::from([0x0; 32]));
+            assert_eq!(dao.get_task(2).reviewer,AccountId::from([0x0; 32]));
+
+            set_sender(accounts.charlie);
+            assert_eq!(dao.reassign_task(1,accounts.charlie,accounts.alice),Err(types::DaoError::IneligibleCaller));
+
+            set_sender(accounts.alice);
+            assert_eq!(dao.reassign_task(3,accounts.charlie,accounts.alice),Err(types::DaoError::TaskDoesNotExist));
+            assert_eq!(dao.reassign_task(1,accounts.alice,accounts.alice),Err(types::DaoError::SelfReview));
+            assert_eq!(dao.reassign_task(1,accounts.django,accounts.alice),Err(types::DaoError::MemberDoesNotExist));
+            assert!(dao.reassign_task(1,accounts.charlie,accounts.alice).is_ok());
+            assert_eq!(dao.get_task(1).assignee,accounts.charlie);
+            assert_eq!(dao.get_member_task_ids(accounts.charlie),vec![1]);
+
+            // The new reviewer must hold the Reviewer role
+            assert_eq!(dao.reassign_task(2,accounts.alice,accounts.charlie),Err(types::DaoError::MissingRole));
+            grant_role(&mut dao,types::REVIEWER,accounts.charlie);
+            assert!(dao.reassign_task(2,accounts.alice,accounts.charlie).is_ok());
+            assert_eq!(dao.get_task(2).reviewer,accounts.charlie);
+
+            submit_for_review(&mut dao,1);
+            assert!(dao.review_task(1,String::from("Good"),10).is_ok());
+            assert_eq!(dao.reassign_task(1,accounts.charlie,accounts.alice),Err(types::DaoError::TaskAlreadyDone));
+        }
+
+        #[ink::test]
+        fn expulsion_proposal_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+            assert!(dao.add_member(accounts.charlie).is_ok());
+
+            let duration = 100000;
+
+            set_sender(accounts.bob);
+            assert_eq!(dao.create_governance_proposal(String::from("General"),duration,types::ProposalKind::General),Err(types::DaoError::InvalidProposalKind));
+            assert!(dao.create_governance_proposal(String::from("Expel Charlie"),duration,types::ProposalKind::Expulsion(accounts.charlie)).is_ok());
+            assert_eq!(dao.get_proposal_kind(1),types::ProposalKind::Expulsion(accounts.charlie));
+            assert_eq!(dao.get_proposal_kind(2),types::ProposalKind::General);
+            assert!(dao.vote(1,true).is_ok());
+
+            set_block_timestamp(duration + 1);
+            assert!(dao.finalize_vote(1).is_ok());
+
+            assert_eq!(dao.get_members(),vec![accounts.alice,accounts.bob]);
+        }
+
+        #[ink::test]
+        fn revalidate_member_without_token_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+
+            // Bob was added by the owner and is not bound to a token
+            set_sender(accounts.charlie);
+            assert_eq!(dao.get_member_token(accounts.bob),None);
+            assert_eq!(dao.revalidate_member(accounts.bob),Err(types::DaoError::MembershipStillValid));
+            assert_eq!(dao.revalidate_member(accounts.charlie),Err(types::DaoError::MemberDoesNotExist));
+        }
+
+        #[ink::test]
+        fn allowlist_policy_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert_eq!(dao.get_eligibility_policy(),EligibilityPolicy::Psp34(vec![token_address()]));
+
+            // A PSP34 policy needs the token the membership is bound to
+            set_sender(accounts.django);
+            assert_eq!(dao.join_dao(Credential::None),Err(types::DaoError::NotEligibleForMembership));
+
+            set_sender(accounts.alice);
+            assert!(dao.set_eligibility_policy(EligibilityPolicy::Allowlist(vec![accounts.django])).is_ok());
+
+            set_sender(accounts.django);
+            assert!(dao.join_dao(Credential::None).is_ok());
+            assert_eq!(dao.get_member_token(accounts.django),None);
+
+            set_sender(accounts.eve);
+            assert_eq!(dao.join_dao(Credential::None),Err(types::DaoError::NotEligibleForMembership));
+
+            assert_eq!(dao.get_members(),vec![accounts.alice,accounts.django]);
+        }
+
+        #[ink::test]
+        fn join_with_proof_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+
+            // Allowlist with Django and Eve
+            let django = merkle_leaf(accounts.django);
+            let eve = merkle_leaf(accounts.eve);
+            let root = merkle_node(django,eve);
+
+            set_sender(accounts.django);
+            assert_eq!(dao.join_with_proof(vec![Hash::from(eve)]),Err(types::DaoError::AllowlistNotSet));
+            assert_eq!(dao.set_merkle_root(Some(Hash::from(root))),Err(types::DaoError::MissingRole));
+            set_sender(accounts.alice);
+            assert!(dao.set_merkle_root(Some(Hash::from(root))).is_ok());
+            assert_eq!(dao.get_merkle_root(),Some(Hash::from(root)));
+
+            set_sender(accounts.django);
+            assert!(dao.join_with_proof(vec![Hash::from(eve)]).is_ok());
+
+            set_sender(accounts.eve);
+            assert_eq!(dao.join_with_proof(vec![Hash::from(eve)]),Err(types::DaoError::NotEligibleForMembership));
+            assert!(dao.join_with_proof(vec![Hash::from(django)]).is_ok());
+
+            set_sender(accounts.frank);
+            assert_eq!(dao.join_with_proof(vec![Hash::from(django)]),Err(types::DaoError::NotEligibleForMembership));
+
+            // The NFT policy still applies to join_dao
+            assert_eq!(dao.get_eligibility_policy(),EligibilityPolicy::Psp34(vec![token_address()]));
+            assert_eq!(dao.get_members(),vec![accounts.alice,accounts.django,accounts.eve]);
+
+            // Governance replaces the allowlist with one that has Frank and Django
+            let frank = merkle_leaf(accounts.frank);
+            let root = merkle_node(frank,django);
+            set_sender(accounts.alice);
+            assert!(dao.create_governance_proposal(String::from("New allowlist"),100,types::ProposalKind::SetMerkleRoot(Some(Hash::from(root)))).is_ok());
+            assert!(dao.vote(1,true).is_ok());
+            set_block_timestamp(200);
+            assert!(dao.finalize_vote(1).is_ok());
+            assert_eq!(dao.get_merkle_root(),Some(Hash::from(root)));
+
+            set_sender(accounts.frank);
+            assert!(dao.join_with_proof(vec![Hash::from(django)]).is_ok());
+        }
+
+        #[ink::test]
+        fn join_with_proof_under_merkle_policy_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+
+            let django = merkle_leaf(accounts.django);
+            let eve = merkle_leaf(accounts.eve);
+            let root = merkle_node(django,eve);
+
+            // A Merkle eligibility policy is accepted without a separate allowlist
+            assert!(dao.set_eligibility_policy(EligibilityPolicy::MerkleRoot(Hash::from(root))).is_ok());
+            assert_eq!(dao.get_merkle_root(),None);
+
+            set_sender(accounts.django);
+            assert!(dao.join_with_proof(vec![Hash::from(eve)]).is_ok());
+            set_sender(accounts.frank);
+            assert_eq!(dao.join_with_proof(vec![Hash::from(eve)]),Err(types::DaoError::NotEligibleForMembership));
+        }
+
+        #[ink::test]
+        fn join_with_invite_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+
+            let secret = b"hackathon".to_vec();
+            let commitment = invite::invite_commitment(&secret);
+
+            // Only members can invite, each invite admits a bounded number of members
+            set_sender(accounts.charlie);
+            assert_eq!(dao.create_invite(commitment,None,1000,2),Err(types::DaoError::MemberDoesNotExist));
+
+            set_sender(accounts.bob);
+            assert_eq!(dao.create_invite(commitment,None,0,2),Err(types::DaoError::InvalidInvite));
+            assert_eq!(dao.create_invite(commitment,None,1000,0),Err(types::DaoError::InvalidInvite));
+            assert_eq!(dao.create_invite(commitment,None,1000,types::MAX_INVITE_USES + 1),Err(types::DaoError::InvalidInvite));
+            assert!(dao.create_invite(commitment,None,1000,2).is_ok());
+
+            set_sender(accounts.django);
+            assert_eq!(dao.join_with_invite(b"wrong".to_vec()),Err(types::DaoError::InviteDoesNotExist));
+            assert!(dao.join_with_invite(secret.clone()).is_ok());
+            set_sender(accounts.eve);
+            assert!(dao.join_with_invite(secret.clone()).is_ok());
+
+            set_sender(accounts.frank);
+            assert_eq!(dao.join_with_invite(secret.clone()),Err(types::DaoError::InviteExhausted));
+            assert_eq!(dao.get_invite(commitment).map(|invite| invite.uses),Some(2));
+
+            assert_eq!(dao.get_inviter(accounts.django),Some(accounts.bob));
+            assert_eq!(dao.get_invitees(accounts.bob),vec![accounts.django,accounts.eve]);
+            assert_eq!(dao.get_members(),vec![accounts.alice,accounts.bob,accounts.django,accounts.eve]);
+        }
+
+        #[ink::test]
+        fn personal_invites_work() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+
+            let secret = b"for django".to_vec();
+            let commitment = invite::invite_commitment(&secret);
+
+            set_sender(accounts.bob);
+            assert!(dao.create_invite(commitment,Some(accounts.django),1000,1).is_ok());
+
+            // The secret is bound to Django
+            set_sender(accounts.eve);
+            assert_eq!(dao.join_with_invite(secret.clone()),Err(types::DaoError::IneligibleCaller));
+
+            // Invites stop working once the inviter has left
+            set_sender(accounts.bob);
+            assert!(dao.leave_dao().is_ok());
+            set_sender(accounts.django);
+            assert_eq!(dao.join_with_invite(secret.clone()),Err(types::DaoError::InviterNotMember));
+            assert_eq!(dao.get_members(),vec![accounts.alice]);
+        }
+
+        fn merkle_leaf(account: AccountId) -> [u8; 32] {
+            let mut leaf = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(&account, &mut leaf);
+            leaf
+        }
+
+        fn merkle_node(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+            let (first,second) = if a <= b { (a,b) } else { (b,a) };
+            let mut input = Vec::new();
+            input.extend_from_slice(&first);
+            input.extend_from_slice(&second);
+            let mut node = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&input, &mut node);
+            node
+        }
+
+        #[ink::test]
+        fn vouching_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+            assert!(dao.add_member(accounts.charlie).is_ok());
+
+            // Bob and Charlie earn 100 points each
+            assert!(dao.create_task(String::from("Task 1"),accounts.bob,accounts.alice,1000000,100,1).is_ok());
+            assert!(dao.create_task(String::from("Task 2"),accounts.charlie,accounts.alice,1000000,100,1).is_ok());
+            submit_for_review(&mut dao,1);
+            assert!(dao.review_task(1,String::from("Good"),100).is_ok());
+            submit_for_review(&mut dao,2);
+            assert!(dao.review_task(2,String::from("Good"),100).is_ok());
+
+            set_sender(accounts.bob);
+            assert_eq!(dao.vouch(accounts.django),Err(types::DaoError::VouchingDisabled));
+
+            set_sender(accounts.alice);
+            let config = types::VouchConfig {
+                required_vouches: 2,
+                min_points: 50,
+                stake: 30,
+                lock_period: 1000,
+            };
+            assert!(dao.set_vouch_config(config).is_ok());
+
+            // Alice has no points to stake
+            assert_eq!(dao.vouch(accounts.django),Err(types::DaoError::InsufficientPoints));
+
+            set_sender(accounts.bob);
+            assert!(dao.vouch(accounts.django).is_ok());
+            assert_eq!(dao.vouch(accounts.django),Err(types::DaoError::AlreadyVouched));
+            assert_eq!(dao.get_member_points(accounts.bob),70u32);
+
+            set_sender(accounts.charlie);
+            assert!(dao.vouch(accounts.django).is_ok());
+            assert_eq!(dao.get_members(),vec![accounts.alice,accounts.bob,accounts.charlie,accounts.django]);
+
+            // The stake is locked right after admission
+            assert_eq!(dao.withdraw_vouch(accounts.django),Err(types::DaoError::VouchLocked));
+
+            // Django gets expelled and the stakes are lost
+            set_sender(accounts.alice);
+            assert!(dao.remove_member(accounts.django).is_ok());
+            assert_eq!(dao.get_vouch_stake(accounts.bob,accounts.django),0u32);
+            assert_eq!(dao.get_member_points(accounts.bob),70u32);
+            assert_eq!(dao.get_member_points(accounts.charlie),70u32);
+        }
+
+        #[ink::test]
+        fn vouch_stakes_survive_leaving() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+            assert!(dao.add_member(accounts.charlie).is_ok());
+            assert!(dao.create_task(String::from("Task 1"),accounts.bob,accounts.alice,1000000,100,1).is_ok());
+            assert!(dao.create_task(String::from("Task 2"),accounts.charlie,accounts.alice,1000000,100,1).is_ok());
+            submit_for_review(&mut dao,1);
+            assert!(dao.review_task(1,String::from("Good"),100).is_ok());
+            submit_for_review(&mut dao,2);
+            assert!(dao.review_task(2,String::from("Good"),100).is_ok());
+            assert!(dao.set_vouch_config(types::VouchConfig {
+                required_vouches: 2,
+                min_points: 0,
+                stake: 30,
+                lock_period: 1000,
+            }).is_ok());
+
+            // Bob and Charlie vouch for Django and Eve
+            for voucher in [accounts.bob,accounts.charlie] {
+                set_sender(voucher);
+                assert!(dao.vouch(accounts.django).is_ok());
+                assert!(dao.vouch(accounts.eve).is_ok());
+            }
+            assert_eq!(dao.get_member_points(accounts.bob),40u32);
+
+            // Eve leaves within the lock period, the stakes stay locked until it ends
+            set_sender(accounts.eve);
+            assert!(dao.leave_dao().is_ok());
+            assert_eq!(dao.get_vouch_stake(accounts.bob,accounts.eve),30u32);
+            set_sender(accounts.bob);
+            assert_eq!(dao.withdraw_vouch(accounts.eve),Err(types::DaoError::VouchLocked));
+
+            // Django leaves while an expulsion is being voted, which decides the stakes
+            assert!(dao.create_governance_proposal(String::from("Expel Django"),100,types::ProposalKind::Expulsion(accounts.django)).is_ok());
+            assert!(dao.vote(1,true).is_ok());
+            set_sender(accounts.django);
+            assert!(dao.leave_dao().is_ok());
+            assert_eq!(dao.get_vouch_stake(accounts.bob,accounts.django),30u32);
+
+            set_block_timestamp(2000);
+            set_sender(accounts.bob);
+            assert_eq!(dao.withdraw_vouch(accounts.django),Err(types::DaoError::VouchLocked));
+            assert!(dao.finalize_vote(1).is_ok());
+            assert_eq!(dao.get_vouch_stake(accounts.bob,accounts.django),0u32);
+            assert_eq!(dao.withdraw_vouch(accounts.django),Err(types::DaoError::VouchDoesNotExist));
+
+            // The lock period is over for Eve's vouchers
+            assert!(dao.withdraw_vouch(accounts.eve).is_ok());
+            assert_eq!(dao.get_member_points(accounts.bob),70u32);
+        }
+
+        #[ink::test]
+        fn membership_expiry_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.set_membership_terms(types::MembershipTerms {
+                duration: 1000,
+                renewal_fee: 0,
+                require_token: false,
+            }).is_ok());
+            assert!(dao.add_member(accounts.bob).is_ok());
+            assert!(dao.add_member(accounts.charlie).is_ok());
+            assert!(dao.set_member_expiry(accounts.charlie,Some(5000)).is_ok());
+
+            assert_eq!(dao.get_member_expiry(accounts.bob),Some(1000));
+            assert_eq!(dao.get_members_expiring_before(2000),vec![accounts.bob]);
+
+            // Bob's membership runs out but his history stays
+            set_block_timestamp(1500);
+            set_sender(accounts.bob);
+            assert_eq!(dao.create_proposal(String::from("Proposal 1"),1000),Err(types::DaoError::MembershipExpired));
+            assert_eq!(dao.get_members(),vec![accounts.alice,accounts.bob,accounts.charlie]);
+
+            assert!(dao.renew_membership().is_ok());
+            assert_eq!(dao.get_member_expiry(accounts.bob),Some(2500));
+            assert!(dao.create_proposal(String::from("Proposal 1"),1000).is_ok());
+
+            // Once memberships stop expiring, past expiries no longer apply
+            set_block_timestamp(6000);
+            set_sender(accounts.charlie);
+            assert_eq!(dao.create_proposal(String::from("Proposal 2"),1000),Err(types::DaoError::MembershipExpired));
+            set_sender(accounts.alice);
+            assert!(dao.set_membership_terms(types::MembershipTerms {
+                duration: 0,
+                renewal_fee: 0,
+                require_token: false,
+            }).is_ok());
+            assert_eq!(dao.get_member_expiry(accounts.charlie),None);
+            assert!(dao.get_members_expiring_before(10000).is_empty());
+            set_sender(accounts.charlie);
+            assert!(dao.create_proposal(String::from("Proposal 2"),1000).is_ok());
+        }
+
+        #[ink::test]
+        fn inactive_members_excluded_from_quorum() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            let actions = dao.get_number_of_actions();
+            assert!(dao.add_member(accounts.bob).is_ok());
+            assert!(dao.add_member(accounts.charlie).is_ok());
+            assert!(dao.set_inactivity_period(1000).is_ok());
+
+            // Quorum of 50% of the active members
+            assert!(dao.submit_action(types::AdminAction::SetQuorum(50)).is_ok());
+            assert!(dao.execute_action(actions + 1).is_ok());
+            assert_eq!(dao.submit_action(types::AdminAction::SetQuorum(150)),Ok(()));
+            assert_eq!(dao.execute_action(actions + 2),Err(types::DaoError::InvalidQuorum));
+
+            // Only Bob is still around
+            set_block_timestamp(2000);
+            assert_eq!(dao.get_number_of_active_members(),0u32);
+
+            set_sender(accounts.bob);
+            assert!(dao.create_proposal(String::from("Proposal 1"),100).is_ok());
+            assert!(dao.vote(1,true).is_ok());
+            assert!(dao.is_member_active(accounts.bob));
+            assert!(!dao.is_member_active(accounts.charlie));
+            assert_eq!(dao.get_last_activity(accounts.bob),2000);
+
+            set_block_timestamp(2500);
+            assert_eq!(dao.get_number_of_active_members(),1u32);
+            assert!(dao.finalize_vote(1).is_ok());
+            assert_eq!(dao.get_proposal_vote(1).vote_status,types::VoteStatus::Passed);
+
+            // Without any active member a lone yes vote does not pass
+            assert!(dao.create_proposal(String::from("Proposal 2"),100).is_ok());
+            assert!(dao.vote(2,true).is_ok());
+            set_block_timestamp(5000);
+            assert_eq!(dao.get_number_of_active_members(),0u32);
+            assert!(dao.finalize_vote(2).is_ok());
+            assert_eq!(dao.get_proposal_vote(2).vote_status,types::VoteStatus::Failed);
+
+            // A minimum number of votes applies on top of the quorum
+            set_sender(accounts.alice);
+            assert!(dao.submit_action(types::AdminAction::SetMinTurnout(2)).is_ok());
+            assert!(dao.execute_action(actions + 3).is_ok());
+            assert_eq!(dao.get_min_turnout(),2u32);
+
+            set_sender(accounts.bob);
+            assert!(dao.create_proposal(String::from("Proposal 3"),100).is_ok());
+            assert!(dao.vote(3,true).is_ok());
+            set_block_timestamp(5100);
+            assert_eq!(dao.get_number_of_active_members(),1u32);
+            assert!(dao.finalize_vote(3).is_ok());
+            assert_eq!(dao.get_proposal_vote(3).vote_status,types::VoteStatus::Failed);
+        }
+
+        #[ink::test]
+        fn roles_work() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            let actions = dao.get_number_of_actions();
+            assert!(dao.add_member(accounts.bob).is_ok());
+            assert!(dao.add_member(accounts.charlie).is_ok());
+            assert_eq!(dao.get_roles(accounts.alice),types::ROLES.to_vec());
+
+            // Bob needs the matching role for privileged messages
+            set_sender(accounts.bob);
+            assert_eq!(dao.add_member(accounts.django),Err(types::DaoError::MissingRole));
+            assert_eq!(dao.create_project(String::from("Project 1")),Err(types::DaoError::MissingRole));
+
+            // Only Reviewers can be named reviewer of a task
+            assert_eq!(dao.create_task(String::from("Task 1"),accounts.bob,accounts.charlie,1000000,100,1),Err(types::DaoError::MissingRole));
+            assert!(dao.create_task(String::from("Task 1"),accounts.bob,accounts.alice,1000000,100,1).is_ok());
+
+            // Governance grants Bob the MembershipManager role
+            assert!(dao.create_governance_proposal(String::from("Bob manages members"),100,types::ProposalKind::GrantRole(types::MEMBERSHIP_MANAGER,accounts.bob)).is_ok());
+            assert!(dao.vote(1,true).is_ok());
+            set_block_timestamp(200);
+            assert!(dao.finalize_vote(1).is_ok());
+            assert!(dao.has_role(types::MEMBERSHIP_MANAGER,accounts.bob));
+            assert_eq!(dao.get_role_members(types::MEMBERSHIP_MANAGER),vec![accounts.alice,accounts.bob]);
+            assert!(dao.add_member(accounts.django).is_ok());
+
+            // Roles can only be granted to members, and unknown roles are rejected
+            assert_eq!(dao.create_governance_proposal(String::from("Eve"),100,types::ProposalKind::GrantRole(types::REVIEWER,accounts.eve)),Err(types::DaoError::MemberDoesNotExist));
+            set_sender(accounts.alice);
+            assert_eq!(dao.submit_action(types::AdminAction::GrantRole(42,accounts.charlie)),Ok(()));
+            assert_eq!(dao.execute_action(actions + 1),Err(types::DaoError::InvalidRole));
+
+            // Bob gives the role up
+            set_sender(accounts.bob);
+            assert!(dao.renounce_role(types::MEMBERSHIP_MANAGER).is_ok());
+            assert_eq!(dao.renounce_role(types::MEMBERSHIP_MANAGER),Err(types::DaoError::RoleNotGranted));
+            assert_eq!(dao.remove_member(accounts.django),Err(types::DaoError::MissingRole));
+        }
+
+        #[ink::test]
+        fn tiers_work() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+            grant_role(&mut dao,types::REVIEWER,accounts.bob);
+
+            let tier = |name: &str, min_points: u32| types::Tier { name: String::from(name), min_points };
+            assert_eq!(dao.set_tiers(vec![tier("Core",100),tier("Contributor",10)]),Err(types::DaoError::InvalidTiers));
+            assert!(dao.set_tiers(vec![tier("Contributor",10),tier("Core",100),tier("Steward",200)]).is_ok());
+            assert!(dao.set_tier_requirements(types::TierRequirements { create_proposal: 2, review_high_priority: 3 }).is_ok());
+
+            // Bob has no points yet and cannot create proposals
+            set_sender(accounts.bob);
+            assert_eq!(dao.get_member_tier(accounts.bob),None);
+            assert_eq!(dao.create_proposal(String::from("Proposal 1"),1000),Err(types::DaoError::TierTooLow));
+
+            // Bob reaches Core by completing a task
+            set_sender(accounts.alice);
+            assert!(dao.create_task(String::from("Task 1"),accounts.bob,accounts.alice,1000000,100,1).is_ok());
+            submit_for_review(&mut dao,1);
+            assert!(dao.review_task(1,String::from("Good"),100).is_ok());
+            assert_eq!(dao.get_member_tier_level(accounts.bob),2u32);
+            assert_eq!(dao.get_member_tier(accounts.bob),Some(tier("Core",100)));
+
+            set_sender(accounts.bob);
+            assert!(dao.create_proposal(String::from("Proposal 1"),1000).is_ok());
+
+            // Only Stewards review high priority tasks
+            set_sender(accounts.alice);
+            assert!(dao.create_task(String::from("Task 2"),accounts.alice,accounts.bob,1000000,10,3).is_ok());
+            set_sender(accounts.bob);
+            submit_for_review(&mut dao,2);
+            assert_eq!(dao.review_task(2,String::from("Good"),10),Err(types::DaoError::TierTooLow));
+        }
+
+        #[ink::test]
+        fn member_profile_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+
+            let profile = types::MemberProfile {
+                display_name: String::from("Bob"),
+                avatar_uri: String::from("ipfs://avatar"),
+                skills: vec![String::from("rust"),String::from("design")],
+                contact_hash: Hash::from([0x01; 32]),
+            };
+
+            set_sender(accounts.charlie);
+            assert_eq!(dao.set_member_profile(profile.clone()),Err(types::DaoError::MemberDoesNotExist));
+
+            set_sender(accounts.bob);
+            assert_eq!(dao.get_member_profile(accounts.bob),None);
+            assert!(dao.set_member_profile(profile.clone()).is_ok());
+            assert_eq!(dao.get_member_profile(accounts.bob),Some(profile.clone()));
+
+            let mut too_many_skills = profile.clone();
+            too_many_skills.skills = vec![String::from("rust"); types::MAX_PROFILE_SKILLS + 1];
+            assert_eq!(dao.set_member_profile(too_many_skills),Err(types::DaoError::InvalidProfile));
+
+            // The profile goes away with the membership
+            assert!(dao.leave_dao().is_ok());
+            assert_eq!(dao.get_member_profile(accounts.bob),None);
+            assert_eq!(dao.remove_member_profile(),Err(types::DaoError::ProfileDoesNotExist));
+        }
+
+        #[ink::test]
+        fn leaderboard_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+            assert!(dao.add_member(accounts.charlie).is_ok());
+            assert!(dao.create_project(String::from("Project 1")).is_ok());
+            for member in [accounts.alice,accounts.bob,accounts.charlie] {
+                set_sender(member);
+                assert!(dao.join_project(1).is_ok());
+            }
+
+            set_sender(accounts.alice);
+            assert!(dao.create_task(String::from("Task 1"),accounts.bob,accounts.alice,1000000,50,1).is_ok());
+            assert!(dao.create_project_task(1,String::from("Task 2"),accounts.charlie,accounts.alice,1000000,30,1).is_ok());
+            assert!(dao.create_project_task(1,String::from("Task 3"),accounts.bob,accounts.alice,1000000,10,1).is_ok());
+
+            set_block_timestamp(1000);
+            submit_for_review(&mut dao,1);
+            assert!(dao.review_task(1,String::from("Good"),50).is_ok());
+            set_block_timestamp(2000);
+            submit_for_review(&mut dao,2);
+            assert!(dao.review_task(2,String::from("Good"),30).is_ok());
+            set_block_timestamp(3000);
+            submit_for_review(&mut dao,3);
+            assert!(dao.review_task(3,String::from("Good"),10).is_ok());
+
+            assert_eq!(dao.get_leaderboard(0,10),vec![(accounts.bob,60),(accounts.charlie,30),(accounts.alice,0)]);
+            assert_eq!(dao.get_leaderboard(1,1),vec![(accounts.charlie,30)]);
+            assert_eq!(dao.get_member_rank(accounts.alice),Some(3));
+            assert_eq!(dao.get_member_rank(accounts.eve),None);
+
+            // Only points from the project's tasks count for the project
+            assert_eq!(dao.get_project_leaderboard(1,0,10),vec![(accounts.charlie,30),(accounts.bob,10)]);
+            assert_eq!(dao.get_project_points(1,accounts.bob),10);
+
+            assert_eq!(dao.get_leaderboard_between(0,1500,0,10),vec![(accounts.bob,50)]);
+            assert_eq!(dao.get_leaderboard_between(1500,3500,0,10),vec![(accounts.charlie,30),(accounts.bob,10)]);
+        }
+
+        #[ink::test]
+        fn kudos_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+
+            let message = Hash::from([0x02; 32]);
+            assert_eq!(dao.give_kudos(accounts.bob,5,message),Err(types::DaoError::KudosDisabled));
+
+            assert!(dao.set_kudos_config(types::KudosConfig { allowance: 10, epoch_length: 1000, transfers_enabled: false }).is_ok());
+            assert_eq!(dao.give_kudos(accounts.alice,5,message),Err(types::DaoError::SelfTransfer));
+            assert_eq!(dao.give_kudos(accounts.eve,5,message),Err(types::DaoError::MemberDoesNotExist));
+
+            assert!(dao.give_kudos(accounts.bob,6,message).is_ok());
+            assert_eq!(dao.get_member_points(accounts.bob),6u32);
+            assert_eq!(dao.get_kudos_left(accounts.alice),4u32);
+            assert_eq!(dao.give_kudos(accounts.bob,5,message),Err(types::DaoError::KudosAllowanceExceeded));
+
+            // The allowance is back in the next epoch
+            set_block_timestamp(1000);
+            assert_eq!(dao.get_kudos_left(accounts.alice),10u32);
+            assert!(dao.give_kudos(accounts.bob,5,message).is_ok());
+            assert_eq!(dao.get_member_points(accounts.bob),11u32);
+
+            // Earned points only move when transfers are enabled
+            set_sender(accounts.bob);
+            assert_eq!(dao.transfer_points(accounts.alice,3),Err(types::DaoError::PointTransfersDisabled));
+            set_sender(accounts.alice);
+            assert!(dao.set_kudos_config(types::KudosConfig { allowance: 10, epoch_length: 1000, transfers_enabled: true }).is_ok());
+            set_sender(accounts.bob);
+            assert_eq!(dao.transfer_points(accounts.alice,20),Err(types::DaoError::InsufficientPoints));
+            assert!(dao.transfer_points(accounts.alice,3).is_ok());
+            assert_eq!(dao.get_member_points(accounts.bob),8u32);
+            assert_eq!(dao.get_member_points(accounts.alice),3u32);
+        }
+
+        #[ink::test]
+        fn give_epoch_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+            assert!(dao.add_member(accounts.charlie).is_ok());
+
+            assert_eq!(dao.allocate(accounts.bob,10),Err(types::DaoError::NoOpenEpoch));
+            assert!(dao.start_epoch(1000,100,types::EpochReward::Points).is_ok());
+            assert_eq!(dao.start_epoch(1000,100,types::EpochReward::Points),Err(types::DaoError::EpochAlreadyOpen));
+            assert_eq!(dao.get_current_epoch(),Some(1));
+
+            // Members that join later sit this epoch out
+            assert!(dao.add_member(accounts.django).is_ok());
+            assert_eq!(dao.allocate(accounts.django,10),Err(types::DaoError::NotAParticipant));
+
+            assert!(dao.allocate(accounts.bob,60).is_ok());
+            assert_eq!(dao.allocate(accounts.charlie,50),Err(types::DaoError::BudgetExceeded));
+            // Allocating again to Bob replaces the first allocation
+            assert!(dao.allocate(accounts.bob,40).is_ok());
+            assert!(dao.allocate(accounts.charlie,50).is_ok());
+            assert_eq!(dao.get_epoch_budget_left(1,accounts.alice),10u32);
+
+            set_sender(accounts.bob);
+            assert!(dao.allocate(accounts.charlie,30).is_ok());
+            assert_eq!(dao.close_epoch(),Err(types::DaoError::EpochNotFinished));
+
+            set_block_timestamp(1000);
+            assert_eq!(dao.allocate(accounts.alice,10),Err(types::DaoError::EpochEnded));
+            assert!(dao.close_epoch().is_ok());
+
+            // Allocations became points, unspent budget is gone
+            assert_eq!(dao.get_member_points(accounts.bob),40u32);
+            assert_eq!(dao.get_member_points(accounts.charlie),80u32);
+            assert_eq!(dao.get_member_points(accounts.alice),0u32);
+            assert_eq!(dao.get_epoch_results(1),vec![(accounts.alice,0),(accounts.bob,40),(accounts.charlie,80)]);
+            assert_eq!(dao.get_epoch(1).unwrap().total_allocated,120u32);
+            assert_eq!(dao.get_epoch(1).unwrap().status,types::EpochStatus::Closed);
+            assert_eq!(dao.get_current_epoch(),None);
+        }
+
+        #[ink::test]
+        fn former_members_cannot_allocate() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+            assert!(dao.start_epoch(1000,100,types::EpochReward::Points).is_ok());
+
+            // Bob stays a participant of the epoch after leaving but has no budget anymore
+            set_sender(accounts.bob);
+            assert!(dao.leave_dao().is_ok());
+            assert_eq!(dao.allocate(accounts.alice,10),Err(types::DaoError::MemberDoesNotExist));
+        }
+
+        #[ink::test]
+        fn give_epoch_payout_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+            assert!(dao.add_member(accounts.charlie).is_ok());
+
+            let contract = ink::env::test::callee::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract,10000);
+
+            // The payout must be covered by the treasury and is reserved for the epoch
+            assert_eq!(dao.start_epoch(1000,100,types::EpochReward::PayoutShare(types::Asset::Native,20000)),Err(types::DaoError::InsufficientTreasury));
+            assert!(dao.start_epoch(1000,100,types::EpochReward::PayoutShare(types::Asset::Native,1000)).is_ok());
+            assert_eq!(dao.get_available_balance(),9000);
+
+            assert!(dao.allocate(accounts.bob,30).is_ok());
+            set_sender(accounts.bob);
+            assert!(dao.allocate(accounts.charlie,50).is_ok());
+            set_sender(accounts.charlie);
+            assert!(dao.allocate(accounts.alice,20).is_ok());
+
+            // Charlie leaves before the end and their share goes back to the treasury
+            assert!(dao.leave_dao().is_ok());
+            set_block_timestamp(1000);
+            assert!(dao.close_epoch().is_ok());
+            assert_eq!(dao.get_epoch_payout(1,accounts.alice),200);
+            assert_eq!(dao.get_epoch_payout(1,accounts.bob),300);
+            assert_eq!(dao.get_epoch_payout(1,accounts.charlie),0);
+            assert_eq!(dao.get_available_balance(),9500);
+
+            set_sender(accounts.bob);
+            let balance = ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob).unwrap();
+            assert!(dao.claim_epoch_payout(1).is_ok());
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob).unwrap(),balance + 300);
+            assert_eq!(dao.claim_epoch_payout(1),Err(types::DaoError::NothingToClaim));
+            assert_eq!(dao.get_available_balance(),9500);
+
+            // Points are not minted for payout epochs
+            assert_eq!(dao.get_member_points(accounts.bob),0u32);
+        }
+
+        #[ink::test]
+        fn points_decay_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+
+            assert_eq!(dao.set_decay_model(types::DecayModel::HalfLife(0)),Err(types::DaoError::InvalidDecayModel));
+            assert!(dao.set_decay_model(types::DecayModel::HalfLife(1000)).is_ok());
+
+            assert!(dao.create_task(String::from("Task 1"),accounts.bob,accounts.alice,1000000,100,1).is_ok());
+            assert!(dao.create_task(String::from("Task 2"),accounts.bob,accounts.alice,1000000,100,1).is_ok());
+            submit_for_review(&mut dao,1);
+            assert!(dao.review_task(1,String::from("Good"),100).is_ok());
+
+            // Points halve every full period
+            set_block_timestamp(999);
+            assert_eq!(dao.get_member_points(accounts.bob),100u32);
+            set_block_timestamp(2500);
+            assert_eq!(dao.get_member_points(accounts.bob),25u32);
+
+            // New points add to the decayed total, the partial period keeps counting
+            submit_for_review(&mut dao,2);
+            assert!(dao.review_task(2,String::from("Good"),100).is_ok());
+            assert_eq!(dao.get_member_points(accounts.bob),125u32);
+            set_block_timestamp(3000);
+            assert_eq!(dao.get_member_points(accounts.bob),62u32);
+
+            assert_eq!(dao.get_lifetime_points(accounts.bob),200u32);
+
+            // Switching the model settles the old decay, the new one only counts from now
+            assert!(dao.set_decay_model(types::DecayModel::None).is_ok());
+            assert_eq!(dao.get_member_points(accounts.bob),62u32);
+            set_block_timestamp(10000);
+            assert!(dao.set_decay_model(types::DecayModel::Linear { period: 1000, amount: 10 }).is_ok());
+            assert_eq!(dao.get_member_points(accounts.bob),62u32);
+            set_block_timestamp(11000);
+            assert_eq!(dao.get_member_points(accounts.bob),52u32);
+            assert_eq!(dao.total_supply(),62);
+        }
+
+        #[ink::test]
+        fn seasons_work() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+            assert!(dao.set_season_length(1000).is_ok());
+
+            assert!(dao.create_task(String::from("Task 1"),accounts.bob,accounts.alice,1000000,40,1).is_ok());
+            assert!(dao.create_task(String::from("Task 2"),accounts.bob,accounts.alice,1000000,60,1).is_ok());
+            submit_for_review(&mut dao,1);
+            assert!(dao.review_task(1,String::from("Good"),40).is_ok());
+            assert_eq!(dao.get_season_points(1,accounts.bob),40u32);
+
+            // Only admins can close a season early
+            set_sender(accounts.bob);
+            set_block_timestamp(500);
+            assert_eq!(dao.close_season(),Err(types::DaoError::SeasonNotFinished));
+            set_block_timestamp(1000);
+            assert!(dao.close_season().is_ok());
+            assert_eq!(dao.get_current_season(),(2,1000));
+            assert_eq!(dao.get_season(1),Some(types::Season { start: 0, end: 1000 }));
+            assert_eq!(dao.get_season_snapshot(1),vec![(accounts.alice,0),(accounts.bob,40)]);
+
+            // Points of the new season go to a new accumulator
+            set_sender(accounts.alice);
+            submit_for_review(&mut dao,2);
+            assert!(dao.review_task(2,String::from("Good"),60).is_ok());
+            assert_eq!(dao.get_season_points(1,accounts.bob),40u32);
+            assert_eq!(dao.get_season_points(2,accounts.bob),60u32);
+            assert_eq!(dao.get_member_points(accounts.bob),100u32);
+        }
+
+        #[ink::test]
+        fn badge_rules_work() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+
+            let first_task = types::BadgeRule {
+                name: String::from("First task"),
+                achievement: types::Achievement::TasksCompleted(1),
+            };
+            let invalid = types::BadgeRule {
+                name: String::from("Nothing"),
+                achievement: types::Achievement::LifetimePoints(0),
+            };
+
+            assert_eq!(dao.create_governance_proposal(String::from("Invalid badge"),100,types::ProposalKind::AddBadgeRule(invalid)),Err(types::DaoError::InvalidBadgeRule));
+            assert_eq!(dao.create_governance_proposal(String::from("Remove badge"),100,types::ProposalKind::RemoveBadgeRule(1)),Err(types::DaoError::BadgeRuleDoesNotExist));
+
+            // Badge rules are added by governance
+            assert!(dao.create_governance_proposal(String::from("First task badge"),100,types::ProposalKind::AddBadgeRule(first_task.clone())).is_ok());
+            assert!(dao.vote(1,true).is_ok());
+            set_block_timestamp(200);
+            assert!(dao.finalize_vote(1).is_ok());
+            assert_eq!(dao.get_badge_rule_ids(),vec![1]);
+            assert_eq!(dao.get_badge_rule(1),Some(first_task));
+
+            // Completed tasks are counted, nothing is minted without a badge contract
+            assert!(dao.create_task(String::from("Task 1"),accounts.bob,accounts.alice,1000000,10,3).is_ok());
+            submit_for_review(&mut dao,1);
+            assert!(dao.review_task(1,String::from("Good"),10).is_ok());
+            assert_eq!(dao.get_completed_tasks(accounts.bob),(1,1));
+            assert_eq!(dao.get_badge_contract(),None);
+            assert_eq!(dao.claim_badges(accounts.bob),Vec::<u32>::new());
+            assert_eq!(dao.get_member_badges(accounts.bob),Vec::<(u32,types::TokenId)>::new());
+
+            assert!(dao.create_governance_proposal(String::from("Drop badge"),100,types::ProposalKind::RemoveBadgeRule(1)).is_ok());
+            assert!(dao.vote(2,true).is_ok());
+            set_block_timestamp(400);
+            assert!(dao.finalize_vote(2).is_ok());
+            assert_eq!(dao.get_badge_rule_ids(),Vec::<u32>::new());
+            assert_eq!(dao.get_badge_rule(1),None);
+        }
+
+        #[ink::test]
+        fn claim_certificate_checks_work() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+            assert!(dao.create_task(String::from("Task 1"),accounts.bob,accounts.alice,1000000,10,1).is_ok());
+
+            assert_eq!(dao.claim_certificate(1),Err(types::DaoError::CertificatesDisabled));
+
+            set_sender(accounts.bob);
+            assert_eq!(dao.set_certificate_contract(Some(accounts.frank)),Err(types::DaoError::MissingRole));
+            set_sender(accounts.alice);
+            assert!(dao.set_certificate_contract(Some(accounts.frank)).is_ok());
+            assert_eq!(dao.get_certificate_contract(),Some(accounts.frank));
+
+            assert_eq!(dao.claim_certificate(2),Err(types::DaoError::TaskDoesNotExist));
+            assert_eq!(dao.claim_certificate(1),Err(types::DaoError::TaskNotDone));
+            assert_eq!(dao.get_task_certificate(1),None);
+        }
+
+        #[ink::test]
+        fn certificates_and_badges_are_minted() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+
+            let first_task = types::BadgeRule {
+                name: String::from("First task"),
+                achievement: types::Achievement::TasksCompleted(1),
+            };
+            assert!(dao.create_governance_proposal(String::from("First task badge"),100,types::ProposalKind::AddBadgeRule(first_task)).is_ok());
+            assert!(dao.create_governance_proposal(String::from("Badge contract"),100,types::ProposalKind::SetBadgeContract(Some(accounts.frank))).is_ok());
+            assert!(dao.vote(1,true).is_ok());
+            assert!(dao.vote(2,true).is_ok());
+            set_block_timestamp(200);
+            assert!(dao.finalize_vote(1).is_ok());
+            assert!(dao.finalize_vote(2).is_ok());
+            assert_eq!(dao.get_badge_contract(),Some(accounts.frank));
+            assert!(dao.set_certificate_contract(Some(accounts.django)).is_ok());
+
+            // Reviewing the task mints its certificate and the badge it earns
+            assert!(dao.create_task(String::from("Task 1"),accounts.bob,accounts.alice,1000000,10,3).is_ok());
+            submit_for_review(&mut dao,1);
+            assert!(dao.review_task(1,String::from("Good"),10).is_ok());
+
+            let dao_account = test::callee::<ink::env::DefaultEnvironment>();
+            assert_eq!(dao.get_task_certificate(1),Some(Id::Bytes(scale::Encode::encode(&(dao_account,1u32)))));
+            assert_eq!(dao.get_member_badges(accounts.bob),vec![(1,Id::U32(1))]);
+
+            // Neither is minted twice
+            assert_eq!(dao.claim_certificate(1),Err(types::DaoError::CertificateAlreadyMinted));
+            assert_eq!(dao.claim_badges(accounts.bob),Vec::<u32>::new());
+            assert_eq!(dao.get_member_badges(accounts.bob),vec![(1,Id::U32(1))]);
+        }
+
+        #[ink::test]
+        fn points_token_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+            assert!(dao.set_kudos_config(types::KudosConfig { allowance: 10, epoch_length: 1000, transfers_enabled: false }).is_ok());
+
+            let message = Hash::from([0x02; 32]);
+            assert!(dao.give_kudos(accounts.bob,6,message).is_ok());
+            assert_eq!(dao.total_supply(),6);
+            assert_eq!(dao.balance_of(accounts.bob),6);
+            assert_eq!(dao.balance_of(accounts.alice),0);
+
+            // Transfers follow the kudos config, approvals never apply
+            set_sender(accounts.bob);
+            assert_eq!(dao.transfer(accounts.alice,2,Vec::new()),Err(PSP22Error::Custom(String::from("PointTransfersDisabled"))));
+            assert_eq!(dao.approve(accounts.alice,2),Err(PSP22Error::Custom(String::from("ApprovalsDisabled"))));
+            assert_eq!(dao.allowance(accounts.bob,accounts.alice),0);
+
+            set_sender(accounts.alice);
+            assert!(dao.set_kudos_config(types::KudosConfig { allowance: 10, epoch_length: 1000, transfers_enabled: true }).is_ok());
+            set_sender(accounts.bob);
+            assert_eq!(dao.transfer(accounts.alice,7,Vec::new()),Err(PSP22Error::InsufficientBalance));
+            assert!(dao.transfer(accounts.alice,2,Vec::new()).is_ok());
+            assert_eq!(dao.balance_of(accounts.bob),4);
+            assert_eq!(dao.balance_of(accounts.alice),2);
+            assert_eq!(dao.total_supply(),6);
+
+            // Pending decay stays out of the PSP22 view until a balance changes
+            set_sender(accounts.alice);
+            assert!(dao.set_decay_model(types::DecayModel::HalfLife(1000)).is_ok());
+            set_block_timestamp(1000);
+            assert_eq!(dao.get_member_points(accounts.bob),2u32);
+            assert_eq!(dao.balance_of(accounts.bob),4);
+            assert_eq!(dao.balance_of(accounts.bob) + dao.balance_of(accounts.alice),dao.total_supply());
+
+            assert!(dao.transfer(accounts.bob,1,Vec::new()).is_ok());
+            assert_eq!(dao.balance_of(accounts.bob),3);
+            assert_eq!(dao.balance_of(accounts.alice),0);
+            assert_eq!(dao.total_supply(),3);
+        }
+
+        #[ink::test]
+        fn redeem_points_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+            assert!(dao.set_kudos_config(types::KudosConfig { allowance: 10, epoch_length: 1000, transfers_enabled: false }).is_ok());
+            assert!(dao.give_kudos(accounts.bob,10,Hash::from([0x02; 32])).is_ok());
+
+            let invalid = types::RedemptionTerms { asset: types::Asset::Native, rate: 0, pool: 40, length: 1000 };
+            let terms = types::RedemptionTerms { asset: types::Asset::Native, rate: 5, pool: 40, length: 1000 };
+            assert_eq!(dao.create_governance_proposal(String::from("Invalid redemption"),100,types::ProposalKind::OpenRedemption(invalid)),Err(types::DaoError::InvalidRedemption));
+
+            // The pool must be in the treasury
+            let contract = ink::env::test::callee::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract,30);
+            assert_eq!(dao.create_governance_proposal(String::from("Rewards"),100,types::ProposalKind::OpenRedemption(terms.clone())),Err(types::DaoError::InsufficientTreasury));
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract,100);
+
+            set_sender(accounts.bob);
+            assert_eq!(dao.redeem_points(5),Err(types::DaoError::NoOpenRedemption));
+
+            // The rate and the pool are voted by governance
+            set_sender(accounts.alice);
+            assert!(dao.create_governance_proposal(String::from("Rewards"),100,types::ProposalKind::OpenRedemption(terms)).is_ok());
+            assert!(dao.vote(1,true).is_ok());
+            set_block_timestamp(200);
+            assert!(dao.finalize_vote(1).is_ok());
+            assert_eq!(dao.get_current_redemption(),Some(1));
+            assert_eq!(dao.get_redemption(1).unwrap().end,1200);
+            assert_eq!(dao.get_available_balance(),60);
+
+            set_sender(accounts.bob);
+            assert_eq!(dao.redeem_points(9),Err(types::DaoError::RewardPoolExceeded));
+            assert!(dao.redeem_points(6).is_ok());
+            assert_eq!(dao.get_member_points(accounts.bob),4u32);
+            assert_eq!(dao.total_supply(),4);
+            assert_eq!(dao.get_pending_reward(1,accounts.bob),30);
+            assert_eq!(dao.redeem_points(3),Err(types::DaoError::RewardPoolExceeded));
+            assert_eq!(dao.claim_rewards(1),Err(types::DaoError::RedemptionNotFinished));
+            assert_eq!(dao.close_redemption(1),Err(types::DaoError::RedemptionNotFinished));
+
+            // Rewards are pulled once the window has ended
+            set_block_timestamp(1200);
+            assert_eq!(dao.get_current_redemption(),None);
+            assert_eq!(dao.redeem_points(1),Err(types::DaoError::NoOpenRedemption));
+
+            // Closing frees the part of the pool nobody redeemed, the rest stays owed
+            assert!(dao.close_redemption(1).is_ok());
+            assert_eq!(dao.close_redemption(1),Err(types::DaoError::RedemptionAlreadyClosed));
+            assert_eq!(dao.get_available_balance(),70);
+
+            let balance = ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob).unwrap();
+
+            assert!(dao.claim_rewards(1).is_ok());
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob).unwrap(),balance + 30);
+            assert_eq!(dao.get_pending_reward(1,accounts.bob),0);
+            assert_eq!(dao.claim_rewards(1),Err(types::DaoError::NothingToClaim));
+            assert_eq!(dao.get_available_balance(),70);
+        }
+
+        #[ink::test]
+        fn treasury_spend_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+
+            let contract = ink::env::test::callee::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract,1000);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(1000);
+            assert!(dao.deposit().is_ok());
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+            assert_eq!(dao.deposit(),Err(types::DaoError::ZeroAmount));
+            assert_eq!(dao.get_treasury_balance(),1000);
+            assert_eq!(dao.get_available_balance(),1000);
+
+            assert_eq!(dao.create_governance_proposal(String::from("Nothing"),100,types::ProposalKind::TreasurySpend(types::Asset::Native,accounts.bob,0)),Err(types::DaoError::ZeroAmount));
+            assert_eq!(dao.create_governance_proposal(String::from("Too much"),100,types::ProposalKind::TreasurySpend(types::Asset::Native,accounts.bob,2000)),Err(types::DaoError::InsufficientTreasury));
+
+            let balance = ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob).unwrap();
+
+            // Funds only leave the treasury through a passed proposal
+            assert!(dao.create_governance_proposal(String::from("Grant"),100,types::ProposalKind::TreasurySpend(types::Asset::Native,accounts.bob,300)).is_ok());
+            assert!(dao.vote(1,true).is_ok());
+            set_block_timestamp(200);
+            assert!(dao.finalize_vote(1).is_ok());
+
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob).unwrap(),balance + 300);
+            assert_eq!(dao.get_treasury_balance(),700);
+            assert_eq!(dao.get_number_of_spends(),1);
+            assert_eq!(dao.get_spend(1),Some(types::TreasurySpend {
+                proposal_id: 1,
+                asset: types::Asset::Native,
+                beneficiary: accounts.bob,
+                amount: 300,
+                timestamp: 200,
+                status: types::SpendStatus::Paid,
+            }));
+        }
+
+        #[ink::test]
+        fn treasury_tokens_work() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+
+            let stablecoin = AccountId::from([0x20; 32]);
+            let asset = types::Asset::PSP22(stablecoin);
+
+            // Only allowlisted tokens can be spent or paid as rewards
+            assert_eq!(dao.create_governance_proposal(String::from("Grant"),100,types::ProposalKind::TreasurySpend(asset.clone(),accounts.bob,10)),Err(types::DaoError::TokenNotAllowed));
+            let terms = types::RedemptionTerms { asset: asset.clone(), rate: 1, pool: 100, length: 1000 };
+            assert_eq!(dao.create_governance_proposal(String::from("Rewards"),100,types::ProposalKind::OpenRedemption(terms)),Err(types::DaoError::TokenNotAllowed));
+
+            set_sender(accounts.bob);
+            assert_eq!(dao.add_treasury_token(stablecoin),Err(types::DaoError::MissingRole));
+
+            set_sender(accounts.alice);
+            assert!(dao.add_treasury_token(stablecoin).is_ok());
+            assert_eq!(dao.add_treasury_token(stablecoin),Err(types::DaoError::TokenAlreadyAllowed));
+            assert_eq!(dao.get_treasury_tokens(),vec![stablecoin]);
+            assert_eq!(dao.create_governance_proposal(String::from("Grant"),100,types::ProposalKind::TreasurySpend(asset,accounts.bob,0)),Err(types::DaoError::ZeroAmount));
+
+            // Allowlisted tokens belong to the DAO and cannot be recovered
+            assert_eq!(dao.recover_tokens(stablecoin,accounts.alice,10),Err(types::DaoError::TokenNotRecoverable));
+
+            assert!(dao.remove_treasury_token(stablecoin).is_ok());
+            assert_eq!(dao.remove_treasury_token(stablecoin),Err(types::DaoError::TokenNotAllowed));
+            assert_eq!(dao.get_treasury_tokens(),Vec::<AccountId>::new());
+            assert_eq!(dao.recover_tokens(stablecoin,accounts.alice,0),Err(types::DaoError::ZeroAmount));
+        }
+
+        #[ink::test]
+        fn payroll_stream_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+
+            let contract = ink::env::test::callee::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract,10000);
+
+            let terms = types::StreamTerms {
+                beneficiary: accounts.bob,
+                asset: types::Asset::Native,
+                rate: 2,
+                start: 1000,
+                end: 3000,
+                cliff: 1500,
+            };
+
+            let outsider = types::StreamTerms { beneficiary: accounts.eve, ..terms.clone() };
+            let late_cliff = types::StreamTerms { cliff: 4000, ..terms.clone() };
+            let too_much = types::StreamTerms { rate: 100, ..terms.clone() };
+            assert_eq!(dao.create_governance_proposal(String::from("Stipend"),100,types::ProposalKind::CreateStream(outsider)),Err(types::DaoError::MemberDoesNotExist));
+            assert_eq!(dao.create_governance_proposal(String::from("Stipend"),100,types::ProposalKind::CreateStream(late_cliff)),Err(types::DaoError::InvalidStream));
+            assert_eq!(dao.create_governance_proposal(String::from("Stipend"),100,types::ProposalKind::CreateStream(too_much)),Err(types::DaoError::InsufficientTreasury));
+
+            assert!(dao.create_governance_proposal(String::from("Stipend"),100,types::ProposalKind::CreateStream(terms.clone())).is_ok());
+            assert!(dao.vote(1,true).is_ok());
+            set_block_timestamp(200);
+            assert!(dao.finalize_vote(1).is_ok());
+            assert_eq!(dao.get_member_streams(accounts.bob),vec![1]);
+            // The whole stream is reserved
+            assert_eq!(dao.get_available_balance(),6000);
+
+            // Nothing can be withdrawn before the cliff
+            set_sender(accounts.bob);
+            set_block_timestamp(1200);
+            assert_eq!(dao.withdraw_stream(1),Err(types::DaoError::NothingToWithdraw));
+
+            let balance = ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob).unwrap();
+
+            set_block_timestamp(2000);
+            assert_eq!(dao.get_withdrawable(1),2000);
+            assert!(dao.withdraw_stream(1).is_ok());
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob).unwrap(),balance + 2000);
+            set_sender(accounts.alice);
+            assert_eq!(dao.withdraw_stream(1),Err(types::DaoError::IneligibleCaller));
+
+            // Cancelling pays what has accrued and frees the rest
+            assert!(dao.create_governance_proposal(String::from("Stop stipend"),100,types::ProposalKind::CancelStream(1)).is_ok());
+            assert!(dao.vote(2,true).is_ok());
+            set_block_timestamp(2500);
+            assert!(dao.finalize_vote(2).is_ok());
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob).unwrap(),balance + 3000);
+            assert_eq!(dao.get_stream(1).unwrap().status,types::StreamStatus::Cancelled);
+            assert_eq!(dao.get_available_balance(),7000);
+
+            set_sender(accounts.bob);
+            set_block_timestamp(3000);
+            assert_eq!(dao.withdraw_stream(1),Err(types::DaoError::NothingToWithdraw));
+
+            // Streams cannot start in the past
+            set_sender(accounts.alice);
+            let backdated = types::StreamTerms { start: 2000, cliff: 4000, end: 5000, rate: 1, ..terms.clone() };
+            assert_eq!(dao.create_governance_proposal(String::from("Stipend"),100,types::ProposalKind::CreateStream(backdated)),Err(types::DaoError::InvalidStream));
+
+            // Removing Bob cancels his remaining streams
+            let next = types::StreamTerms { start: 4000, cliff: 4000, end: 5000, rate: 1, ..terms };
+            assert!(dao.create_governance_proposal(String::from("Stipend"),100,types::ProposalKind::CreateStream(next)).is_ok());
+            assert!(dao.vote(3,true).is_ok());
+            set_block_timestamp(3200);
+            assert!(dao.finalize_vote(3).is_ok());
+            assert_eq!(dao.get_available_balance(),6000);
+
+            assert!(dao.remove_member(accounts.bob).is_ok());
+            assert_eq!(dao.get_stream(2).unwrap().status,types::StreamStatus::Cancelled);
+            assert_eq!(dao.get_available_balance(),7000);
+        }
+
+        #[ink::test]
+        fn review_task_checks_work() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+
+            let mut dao = init_contract();
+            assert!(dao.add_member(accounts.bob).is_ok());
+
+            assert_eq!(dao.create_task(String::from("Task 1"),accounts.alice,accounts.alice,1000000,10,1),Err(types::DaoError::SelfReview));
+            assert!(dao.create_task(String::from("Task 1"),accounts.bob,accounts.alice,1000000,10,1).is_ok());
+
+            // Only submitted tasks are reviewed, and only once
+            assert_eq!(dao.review_task(1,String::from("Good"),10),Err(types::DaoError::TaskNotUnderReview));
+            submit_for_review(&mut dao,1);
+            assert_eq!(dao.review_task(1,String::from("Good"),11),Err(types::DaoError::TooManyPoints));
+            assert!(dao.review_task(1,String::from("Good"),10).is_ok());
+            assert_eq!(dao.review_task(1,String::from("Good"),10),Err(types::DaoError::TaskNotUnderReview));
+
+            assert_eq!(dao.get_member_points(accounts.bob),10u32);
+            assert_eq!(dao.get_completed_tasks(accounts.bob),(1,0));
+        }
+
+        fn submit_for_review(dao: &mut DaoContract, task_id: u32) {
+            let caller = ink::env::caller::<ink::env::DefaultEnvironment>();
+            set_sender(dao.get_task(task_id).assignee);
+            assert!(dao.submit_task(task_id).is_ok());
+            set_sender(caller);
+        }
+
+        fn grant_role(dao: &mut DaoContract, role: types::RoleType, account: AccountId) {
+            assert!(dao.submit_action(types::AdminAction::GrantRole(role,account)).is_ok());
+            let action_id = dao.get_number_of_actions();
+            assert!(dao.execute_action(action_id).is_ok());
+        }
+
+        fn default_accounts() -> test::DefaultAccounts<ink::env::DefaultEnvironment> {
+            test::default_accounts::<Environment>()
+        }
+
+        fn set_sender(sender: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(sender);
+        }
+
+        fn set_block_timestamp(timestamp: Timestamp) {
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(timestamp);
+        }
+
+        fn init_contract() -> DaoContract {
+            let metadata = String::from("Test");
+            let accounts = default_accounts();
+            let mut dao = DaoContract::new(token_address(),metadata,vec![accounts.alice],1);
+            for role in types::ROLES.iter() {
+                grant_role(&mut dao,*role,accounts.alice);
+            }
+            dao
         }
 
         fn token_address() -> AccountId {
@@ -7,10 +7,15 @@ pub mod daomanager {
     use openbrush::contracts::reentrancy_guard::*;
     use openbrush::traits::Storage;
     
+    use ink::prelude::vec;
     use toyota_pkg::{
         impls::daomanager::*,
         traits::daomanager::*,
     };
+    use toyota_pkg::impls::eligibility::types::{
+        Credential,
+        EligibilityPolicy,
+    };
 
 
     #[ink(storage)]
@@ -29,6 +34,7 @@ pub mod daomanager {
         pub fn new(token: AccountId) -> Self {
                 let mut instance = Self::default();
                 instance.daomanager.token = token;
+                instance.daomanager.eligibility = EligibilityPolicy::Psp34(vec![token]);
                 let caller = instance.env().caller();
                 instance._init_with_owner(caller.clone());
                 instance
@@ -53,6 +59,23 @@ pub mod daomanager {
             assert_eq!(daomanager.get_token(), token_address());
         }
 
+        #[ink::test]
+        fn register_with_allowlist_works() {
+            let accounts = default_accounts();
+            set_sender(accounts.alice);
+            let mut daomanager = init_contract();
+
+            assert!(daomanager.set_eligibility_policy(EligibilityPolicy::Allowlist(vec![accounts.bob])).is_ok());
+
+            set_sender(accounts.bob);
+            assert!(daomanager.register().is_ok());
+            assert_eq!(daomanager.register_with_credential(Credential::None),Err(types::DaoManagerError::AlreadyAMember));
+            assert!(daomanager.check_membership(accounts.bob));
+
+            set_sender(accounts.charlie);
+            assert_eq!(daomanager.register(),Err(types::DaoManagerError::NotEligible));
+        }
+
         fn default_accounts() -> test::DefaultAccounts<ink::env::DefaultEnvironment> {
             test::default_accounts::<Environment>()
         }
@@ -0,0 +1,163 @@
+use crate::{
+    impls::dao::types::{
+        Data,
+        DaoError,
+        Achievement,
+        BadgeId,
+        BadgeRule,
+        TokenId,
+    },
+    impls::dao::dao::DaoEvents,
+    traits::badges::DaoBadges,
+};
+use ink::prelude::vec::Vec;
+
+use openbrush::{
+    contracts::psp34::{
+        extensions::mintable::PSP34MintableRef,
+        Id,
+    },
+    traits::{
+        AccountId,
+        Storage,
+    },
+};
+
+pub trait BadgesInternal {
+    fn has_achieved(&self,member: AccountId,achievement: &Achievement) -> bool;
+
+    /// Mints every badge `member` has earned but not received yet, returning the new ones.
+    fn check_badges(&mut self,member: AccountId) -> Vec<BadgeId>;
+
+    fn add_badge_rule_internal(&mut self,rule: BadgeRule) -> BadgeId;
+
+    fn validate_badge_rule(&self,rule: &BadgeRule) -> Result<(),DaoError>;
+
+    /// Mints `token_id` in the badge collection, returning whether it succeeded.
+    fn mint_badge_token(&self,contract: AccountId,to: AccountId,token_id: TokenId) -> bool;
+}
+
+impl<T> DaoBadges for T
+where
+    T: Storage<Data>,
+{
+    default fn claim_badges(&mut self, member: AccountId) -> Vec<BadgeId> {
+        self.check_badges(member)
+    }
+
+    default fn get_badge_contract(&self) -> Option<AccountId> {
+        self.data::<Data>().badge_contract
+    }
+
+    default fn get_badge_rule(&self, badge_id: BadgeId) -> Option<BadgeRule> {
+        self.data::<Data>().badge_rules.get(&badge_id)
+    }
+
+    default fn get_badge_rule_ids(&self) -> Vec<BadgeId> {
+        self.data::<Data>().badge_rule_ids.clone()
+    }
+
+    default fn get_member_badges(&self, member: AccountId) -> Vec<(BadgeId,TokenId)> {
+        self.data::<Data>().member_badges.get(&member).unwrap_or_default()
+    }
+
+    default fn get_completed_tasks(&self, member: AccountId) -> (u32,u32) {
+        (self.data::<Data>().completed_tasks.get(&member).unwrap_or(0),
+            self.data::<Data>().completed_high_priority.get(&member).unwrap_or(0))
+    }
+}
+
+impl<T> BadgesInternal for T
+where
+    T: Storage<Data>,
+{
+    default fn has_achieved(&self,member: AccountId,achievement: &Achievement) -> bool {
+        match achievement {
+            Achievement::Joined => self.data::<Data>().members.contains(&member),
+            Achievement::TasksCompleted(count) => {
+                self.data::<Data>().completed_tasks.get(&member).unwrap_or(0) >= *count
+            },
+            Achievement::HighPriorityTasksCompleted(count) => {
+                self.data::<Data>().completed_high_priority.get(&member).unwrap_or(0) >= *count
+            },
+            Achievement::LifetimePoints(points) => {
+                self.data::<Data>().lifetime_points.get(&member).unwrap_or(0) >= *points
+            },
+        }
+    }
+
+    default fn check_badges(&mut self,member: AccountId) -> Vec<BadgeId> {
+        let mut minted = Vec::new();
+
+        let contract = match self.data::<Data>().badge_contract {
+            Some(contract) => contract,
+            None => return minted,
+        };
+
+        if !self.data::<Data>().members.contains(&member) {
+            return minted
+        }
+
+        let mut badges = self.data::<Data>().member_badges.get(&member).unwrap_or_default();
+        let rule_ids = self.data::<Data>().badge_rule_ids.clone();
+
+        for badge_id in rule_ids.into_iter() {
+            if badges.iter().any(|(owned,_)| *owned == badge_id) {
+                continue
+            }
+
+            let rule = match self.data::<Data>().badge_rules.get(&badge_id) {
+                Some(rule) => rule,
+                None => continue,
+            };
+
+            if !self.has_achieved(member.clone(),&rule.achievement) {
+                continue
+            }
+
+            // A failed mint is retried the next time the badges are checked
+
+            let serial = self.data::<Data>().badge_serial.saturating_add(1);
+            let token_id = Id::U32(serial);
+
+            if !self.mint_badge_token(contract,member.clone(),token_id.clone()) {
+                continue
+            }
+
+            self.data::<Data>().badge_serial = serial;
+            badges.push((badge_id,token_id.clone()));
+            minted.push(badge_id);
+
+            self.emit_badge_minted_event(member.clone(),badge_id,token_id);
+        }
+
+        if !minted.is_empty() {
+            self.data::<Data>().member_badges.insert(&member,&badges);
+        }
+
+        minted
+    }
+
+    default fn add_badge_rule_internal(&mut self,rule: BadgeRule) -> BadgeId {
+        let badge_id = self.data::<Data>().badge_id.saturating_add(1);
+
+        self.data::<Data>().badge_rules.insert(&badge_id,&rule);
+        self.data::<Data>().badge_rule_ids.push(badge_id);
+        self.data::<Data>().badge_id = badge_id;
+
+        badge_id
+    }
+
+    default fn validate_badge_rule(&self,rule: &BadgeRule) -> Result<(),DaoError> {
+        match rule.achievement {
+            Achievement::TasksCompleted(0)
+            | Achievement::HighPriorityTasksCompleted(0)
+            | Achievement::LifetimePoints(0) => Err(DaoError::InvalidBadgeRule),
+            _ => Ok(()),
+        }
+    }
+
+    default fn mint_badge_token(&self,contract: AccountId,to: AccountId,token_id: TokenId) -> bool {
+        PSP34MintableRef::mint(&contract,to,token_id).is_ok()
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    impls::dao::types::{
+        Data,
+        DaoError,
+        TaskId,
+        TaskStatus,
+        TokenId,
+        ADMIN,
+    },
+    impls::dao::roles::only_role,
+    impls::dao::dao::DaoEvents,
+    traits::certificates::DaoCertificates,
+    traits::psp34_certificate::PSP34CertificateRef,
+};
+use ink::env::hash::Blake2x256;
+use ink::prelude::vec;
+use ink::prelude::vec::Vec;
+use scale::Encode;
+
+use openbrush::{
+    contracts::psp34::Id,
+    modifiers,
+    traits::{
+        AccountId,
+        DefaultEnv,
+        Storage,
+    },
+};
+
+pub trait CertificatesInternal {
+    /// Mints the certificate of a done task to its assignee.
+    fn mint_certificate(&mut self,task_id: TaskId) -> Result<(),DaoError>;
+
+    /// Mints `token_id` in the certificate collection, returning whether it succeeded.
+    fn mint_certificate_token(&self,contract: AccountId,to: AccountId,token_id: TokenId,attributes: Vec<(Vec<u8>,Vec<u8>)>) -> bool;
+}
+
+impl<T> DaoCertificates for T
+where
+    T: Storage<Data>,
+{
+    #[modifiers(only_role(ADMIN))]
+    default fn set_certificate_contract(&mut self, contract: Option<AccountId>) -> Result<(),DaoError> {
+        self.data::<Data>().certificate_contract = contract;
+        Ok(())
+    }
+
+    default fn get_certificate_contract(&self) -> Option<AccountId> {
+        self.data::<Data>().certificate_contract
+    }
+
+    default fn claim_certificate(&mut self, task_id: TaskId) -> Result<(),DaoError> {
+        self.mint_certificate(task_id)
+    }
+
+    default fn get_task_certificate(&self, task_id: TaskId) -> Option<TokenId> {
+        self.data::<Data>().task_certificate.get(&task_id)
+    }
+}
+
+impl<T> CertificatesInternal for T
+where
+    T: Storage<Data>,
+{
+    default fn mint_certificate(&mut self,task_id: TaskId) -> Result<(),DaoError> {
+        let contract = match self.data::<Data>().certificate_contract {
+            Some(contract) => contract,
+            None => return Err(DaoError::CertificatesDisabled),
+        };
+
+        let task = match self.data::<Data>().task.get(&task_id) {
+            Some(task) => task,
+            None => return Err(DaoError::TaskDoesNotExist),
+        };
+
+        if task.status != TaskStatus::Done {
+            return Err(DaoError::TaskNotDone)
+        }
+
+        if self.data::<Data>().task_certificate.get(&task_id).is_some() {
+            return Err(DaoError::CertificateAlreadyMinted)
+        }
+
+        // The DAO address is part of the id so that DAOs can share a collection
+
+        let dao = Self::env().account_id();
+        let token_id = Id::Bytes((dao.clone(),task_id).encode());
+
+        let mut review_hash = [0u8; 32];
+        ink::env::hash_encoded::<Blake2x256,_>(&task.review,&mut review_hash);
+
+        let points = self.data::<Data>().task_awarded_points.get(&task_id).unwrap_or(0);
+
+        let attributes: Vec<(Vec<u8>,Vec<u8>)> = vec![
+            (b"task_id".to_vec(),task_id.encode()),
+            (b"dao".to_vec(),dao.encode()),
+            (b"points".to_vec(),points.encode()),
+            (b"review_hash".to_vec(),review_hash.to_vec()),
+        ];
+
+        if !self.mint_certificate_token(contract,task.assignee.clone(),token_id.clone(),attributes) {
+            return Err(DaoError::CertificateMintFailed)
+        }
+
+        self.data::<Data>().task_certificate.insert(&task_id,&token_id);
+
+        self.emit_certificate_minted_event(task.assignee,task_id,token_id);
+
+        Ok(())
+    }
+
+    default fn mint_certificate_token(&self,contract: AccountId,to: AccountId,token_id: TokenId,attributes: Vec<(Vec<u8>,Vec<u8>)>) -> bool {
+        PSP34CertificateRef::mint_certificate(&contract,to,token_id,attributes).is_ok()
+    }
+}
@@ -1,8 +1,15 @@
 use crate::{
+    impls::eligibility::eligibility::verify_merkle_proof,
+    impls::eligibility::types::{
+        Credential,
+        EligibilityPolicy,
+    },
     impls::dao::types::{
+        Asset,
         Data,
         Proposal,
         ProposalId,
+        ProposalKind,
         Project,
         DaoError,
         Vote,
@@ -11,23 +18,49 @@ use crate::{
         TaskStatus,
         TaskId,
         TaskPriority,
+        TokenId,
         ProjectId,
+        RedemptionId,
+        RoleType,
+        SpendId,
+        StreamId,
+        StreamStatus,
+        ADMIN,
+        MEMBERSHIP_MANAGER,
+        PROJECT_LEAD,
+        REVIEWER,
+        ROLES,
+    },
+    impls::dao::roles::{
+        only_role,
+        RolesInternal,
     },
+    impls::dao::badges::BadgesInternal,
+    impls::dao::certificates::CertificatesInternal,
+    impls::dao::decay::DecayInternal,
+    impls::dao::leaderboard::LeaderboardInternal,
+    impls::dao::points_token::PointsInternal,
+    impls::dao::rewards::RewardsInternal,
+    impls::dao::seasons::SeasonsInternal,
+    impls::dao::streams::StreamsInternal,
+    impls::dao::tiers::TiersInternal,
+    impls::dao::treasury::TreasuryInternal,
+    impls::dao::vouching::VouchingInternal,
     traits::dao::ToyotaDao,
 };
 use ink::prelude::vec::Vec;
 use ink::prelude::vec;
-//use ink::Blake2x256;
+use ink::primitives::Hash;
    
 use openbrush::{
     contracts::{
-        ownable::*,
         psp34::*,
         reentrancy_guard::*,
     },
     modifiers,
     traits::{
         AccountId,
+        Balance,
         Storage,
         String,
         Timestamp,
@@ -36,67 +69,217 @@ use openbrush::{
 };
 
 pub trait Internal {
-    fn is_eligible(&self,account: AccountId) -> bool;
+    fn is_eligible(&self,account: AccountId,credential: &Credential) -> bool;
+
+    fn owns_token(&self,account: AccountId,collection: AccountId,token_id: TokenId) -> bool;
 
     fn is_project_member(&self,project_id: ProjectId,account: AccountId) -> bool;
 
+    fn add_member_internal(&mut self,address: AccountId) -> u32;
+
+    fn is_expired(&self,account: AccountId) -> bool;
+
+    fn is_inactive(&self,account: AccountId) -> bool;
+
+    fn record_activity(&mut self,account: AccountId);
+
+    fn active_member_count(&self) -> u32;
+
+    fn create_proposal_internal(&mut self,creator: AccountId,description: String,duration: Timestamp,kind: ProposalKind) -> ProposalId;
+
+    fn execute_proposal(&mut self,proposal_id: ProposalId);
+
+    fn remove_member_internal(&mut self,address: AccountId);
+
+    /// Owner of `task` if they can take it over from `removed` without reviewing their own work
+    fn fallback_assignee(&self,task: &Task,removed: AccountId) -> AccountId;
+
+    /// Owner of `task` if they can review it in place of `removed` without reviewing their own work
+    fn fallback_reviewer(&self,task: &Task,removed: AccountId) -> AccountId;
+
     fn create_task_internal(&mut self,description: String, caller: AccountId,assignee: AccountId, reviewer: AccountId, deadline: Timestamp,
          task_priority: TaskPriority, points: u32) -> TaskId;
 }
 
 pub trait DaoEvents {
     fn emit_member_added_event(&self, member:AccountId, member_id: u32);
+    fn emit_member_removed_event(&self, member:AccountId);
+    fn emit_vouch_cast_event(&self, voucher:AccountId, candidate:AccountId);
+    fn emit_member_invited_event(&self, inviter:AccountId, member:AccountId);
+    fn emit_membership_renewed_event(&self, member:AccountId, expiry: Timestamp);
     fn emit_project_created_event(&self, creator:AccountId, project_id: u32);
     fn emit_proposal_created_event(&self, creator:AccountId, proposal_id: u32);
     fn emit_task_created_event(&self, creator:AccountId, task_id: u32);
+    fn emit_action_submitted_event(&self, signer:AccountId, action_id: u32);
+    fn emit_action_confirmed_event(&self, signer:AccountId, action_id: u32);
+    fn emit_action_executed_event(&self, signer:AccountId, action_id: u32);
+    fn emit_role_granted_event(&self, role: RoleType, account:AccountId);
+    fn emit_role_revoked_event(&self, role: RoleType, account:AccountId);
+    fn emit_tier_changed_event(&self, member:AccountId, tier: u32);
+    fn emit_profile_updated_event(&self, member:AccountId);
+    fn emit_kudos_given_event(&self, sender:AccountId, recipient:AccountId, points: u32, message_hash: Hash);
+    fn emit_points_transferred_event(&self, sender:AccountId, recipient:AccountId, points: u32);
+    fn emit_epoch_started_event(&self, epoch_id: u32);
+    fn emit_epoch_closed_event(&self, epoch_id: u32, total_allocated: u32);
+    fn emit_season_closed_event(&self, season_id: u32);
+    fn emit_badge_minted_event(&self, member:AccountId, badge_id: u32, token_id: TokenId);
+    fn emit_certificate_minted_event(&self, member:AccountId, task_id: TaskId, token_id: TokenId);
+    fn emit_points_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, value: Balance);
+    fn emit_points_redeemed_event(&self, member: AccountId, redemption_id: RedemptionId, points: u32, reward: Balance);
+    fn emit_rewards_claimed_event(&self, member: AccountId, redemption_id: RedemptionId, amount: Balance);
+    fn emit_deposit_event(&self, from: AccountId, amount: Balance);
+    fn emit_treasury_spent_event(&self, spend_id: SpendId, asset: Asset, beneficiary: AccountId, amount: Balance);
+    fn emit_tokens_recovered_event(&self, token: AccountId, to: AccountId, amount: Balance);
+    fn emit_stream_created_event(&self, stream_id: StreamId, beneficiary: AccountId);
+    fn emit_stream_withdrawn_event(&self, stream_id: StreamId, beneficiary: AccountId, amount: Balance);
+    fn emit_stream_cancelled_event(&self, stream_id: StreamId, settled: Balance);
+    fn emit_epoch_payout_claimed_event(&self, member: AccountId, epoch_id: u32, amount: Balance);
 }
 
 impl<T> ToyotaDao for T
 where
-    T: Storage<Data> + Storage<ownable::Data> + Storage<reentrancy_guard::Data>,
+    T: Storage<Data> + Storage<reentrancy_guard::Data>,
 {
 
-    #[modifiers(only_owner)]
+    #[modifiers(only_role(MEMBERSHIP_MANAGER))]
     default fn add_member(&mut self,address: AccountId) -> Result<(),DaoError> {
         if self.data::<Data>().members.contains(&address) {
             return Err(DaoError::MemberAlreadyExists)
         }
 
-        // Dummy
+        let member_id = self.add_member_internal(address.clone());
 
-        let member_id = self.data::<Data>().member_id.saturating_add(1);
-        self.data::<Data>().member_id = member_id;
+        self.emit_member_added_event(address,member_id);
 
-        // Add to the members
+        Ok(())
+    }
 
-        self.data::<Data>().members.push(address.clone());
-        self.data::<Data>().member_token.insert(&address,&member_id);
+    default fn join_dao(&mut self, credential: Credential) -> Result<(),DaoError> {
+        let address = Self::env().caller();
+        if self.data::<Data>().members.contains(&address) {
+            return Err(DaoError::MemberAlreadyExists)
+        }
+
+        // With a PSP34 policy the membership is bound to the token it was granted for
+
+        let token = match (&self.data::<Data>().eligibility,credential.clone()) {
+            (EligibilityPolicy::Psp34(_),Credential::Psp34 { collection, token_id }) => Some((collection,token_id)),
+            (EligibilityPolicy::Psp34(_),_) => return Err(DaoError::NotEligibleForMembership),
+            _ => None,
+        };
+
+        if let Some(token) = token.clone() {
+            if self.data::<Data>().token_member.get(&token).is_some() {
+                return Err(DaoError::TokenAlreadyBound)
+            }
+        }
+
+        if !self.is_eligible(address.clone(),&credential) {
+            return Err(DaoError::NotEligibleForMembership)
+        }
+
+        let member_id = self.add_member_internal(address.clone());
+
+        if let Some(token) = token {
+            self.data::<Data>().member_nft.insert(&address,&token);
+            self.data::<Data>().token_member.insert(&token,&address);
+        }
 
         self.emit_member_added_event(address,member_id);
 
+        Ok(())
+
+    }
+
+    default fn leave_dao(&mut self) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        if !self.data::<Data>().members.contains(&caller) {
+            return Err(DaoError::MemberDoesNotExist)
+        }
+
+        self.remove_member_internal(caller.clone());
+        self.release_vouches(caller.clone());
+
+        self.emit_member_removed_event(caller);
+
         Ok(())
     }
 
-    default fn join_dao(&mut self) -> Result<(),DaoError> {
+    default fn join_with_proof(&mut self, proof: Vec<Hash>) -> Result<(),DaoError> {
         let address = Self::env().caller();
         if self.data::<Data>().members.contains(&address) {
             return Err(DaoError::MemberAlreadyExists)
         }
 
-        if !self.is_eligible(address.clone()) {
-            return Err(DaoError::NotEligibleForMembership)
+        // The allowlist is an onboarding path of its own, a Merkle eligibility
+        // policy is accepted as well
+
+        let root = self.data::<Data>().allowlist_root;
+
+        if let Some(root) = root {
+            if verify_merkle_proof(root,address.clone(),&proof) {
+                let member_id = self.add_member_internal(address.clone());
+
+                self.emit_member_added_event(address,member_id);
+
+                return Ok(())
+            }
         }
 
-        let member_id = self.data::<Data>().member_id.saturating_add(1);
-        self.data::<Data>().member_id = member_id;
+        if matches!(self.data::<Data>().eligibility,EligibilityPolicy::MerkleRoot(_)) {
+            return self.join_dao(Credential::MerkleProof(proof))
+        }
 
-        self.data::<Data>().members.push(address.clone());
-        self.data::<Data>().member_token.insert(&address,&member_id);
+        if root.is_none() {
+            return Err(DaoError::AllowlistNotSet)
+        }
 
-        self.emit_member_added_event(address,member_id);
+        Err(DaoError::NotEligibleForMembership)
+    }
 
+    #[modifiers(only_role(MEMBERSHIP_MANAGER))]
+    default fn set_merkle_root(&mut self, root: Option<Hash>) -> Result<(),DaoError> {
+        self.data::<Data>().allowlist_root = root;
         Ok(())
+    }
 
+    default fn revalidate_member(&mut self,account: AccountId) -> Result<(),DaoError> {
+        if !self.data::<Data>().members.contains(&account) {
+            return Err(DaoError::MemberDoesNotExist)
+        }
+
+        // Members added without a token have nothing to revalidate
+
+        let (collection,token_id) = match self.data::<Data>().member_nft.get(&account) {
+            Some(token) => token,
+            None => return Err(DaoError::MembershipStillValid),
+        };
+
+        if self.owns_token(account.clone(),collection,token_id) {
+            return Err(DaoError::MembershipStillValid)
+        }
+
+        self.remove_member_internal(account.clone());
+        self.release_vouches(account.clone());
+
+        self.emit_member_removed_event(account);
+
+        Ok(())
+    }
+
+    #[modifiers(only_role(MEMBERSHIP_MANAGER))]
+    default fn remove_member(&mut self,address: AccountId) -> Result<(),DaoError> {
+        if !self.data::<Data>().members.contains(&address) {
+            return Err(DaoError::MemberDoesNotExist)
+        }
+
+        self.remove_member_internal(address.clone());
+        self.settle_vouches(address.clone(),true);
+
+        self.emit_member_removed_event(address);
+
+        Ok(())
     }
 
     default fn create_proposal(&mut self,description: String, duration: Timestamp) -> Result<(),DaoError> {
@@ -106,37 +289,121 @@ where
             return Err(DaoError::MemberDoesNotExist)
         }
 
-        let now = Self::env().block_timestamp();
+        if self.is_expired(caller.clone()) {
+            return Err(DaoError::MembershipExpired)
+        }
 
-        let vote = Vote {
-            yes_votes: 0,
-            no_votes: 0,
-            start: now,
-            end: now + duration,
-            vote_status: VoteStatus::InProgress,
-        };
+        if self.member_tier_level(caller.clone()) < self.data::<Data>().tier_requirements.create_proposal {
+            return Err(DaoError::TierTooLow)
+        }
 
-        let proposal_id = self.data::<Data>().proposal_id.saturating_add(1);
+        let proposal_id = self.create_proposal_internal(caller.clone(),description,duration,ProposalKind::General);
 
-        self.data::<Data>().proposal.insert(&proposal_id.clone(),
-            &Proposal {
-                creator: caller.clone(),
-                description: description,
-        });
+        self.emit_proposal_created_event(caller,proposal_id);
 
-        let member_proposals = self.data::<Data>().member_proposals.get(&caller);
+        self.record_activity(caller.clone());
 
-        if let Some(mut proposals) = member_proposals {
-            proposals.push(proposal_id.clone());
-            self.data::<Data>().member_proposals.insert(&caller, &proposals);
-        } else {
-            let proposals = vec![proposal_id.clone()];
-            self.data::<Data>().member_proposals.insert(&caller, &proposals);
+        Ok(())
+    }
+
+    default fn create_governance_proposal(&mut self,description: String, duration: Timestamp, kind: ProposalKind) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        if !self.data::<Data>().members.contains(&caller) {
+            return Err(DaoError::MemberDoesNotExist)
         }
 
-        self.data::<Data>().vote.insert(&proposal_id.clone(),&vote);
+        if self.is_expired(caller.clone()) {
+            return Err(DaoError::MembershipExpired)
+        }
 
-        self.data::<Data>().proposal_id = proposal_id;
+        if self.member_tier_level(caller.clone()) < self.data::<Data>().tier_requirements.create_proposal {
+            return Err(DaoError::TierTooLow)
+        }
+
+        match kind.clone() {
+            ProposalKind::Expulsion(member) => {
+                if !self.data::<Data>().members.contains(&member) {
+                    return Err(DaoError::MemberDoesNotExist)
+                }
+            },
+            ProposalKind::SetEligibilityPolicy(_) => {},
+            ProposalKind::SetMerkleRoot(_) => {},
+            ProposalKind::GrantRole(role,account) => {
+                if !ROLES.contains(&role) {
+                    return Err(DaoError::InvalidRole)
+                }
+                if !self.data::<Data>().members.contains(&account) {
+                    return Err(DaoError::MemberDoesNotExist)
+                }
+                if self.data::<Data>().has_role(role,account) {
+                    return Err(DaoError::RoleAlreadyGranted)
+                }
+            },
+            ProposalKind::RevokeRole(role,account) => {
+                if !self.data::<Data>().has_role(role,account) {
+                    return Err(DaoError::RoleNotGranted)
+                }
+            },
+            ProposalKind::SetBadgeContract(_) => {},
+            ProposalKind::AddBadgeRule(rule) => {
+                self.validate_badge_rule(&rule)?;
+            },
+            ProposalKind::RemoveBadgeRule(badge_id) => {
+                if self.data::<Data>().badge_rules.get(&badge_id).is_none() {
+                    return Err(DaoError::BadgeRuleDoesNotExist)
+                }
+            },
+            ProposalKind::OpenRedemption(terms) => {
+                self.validate_redemption_terms(&terms)?;
+            },
+            ProposalKind::TreasurySpend(asset,_,amount) => {
+                self.validate_spend(&asset,amount)?;
+            },
+            ProposalKind::CreateStream(terms) => {
+                self.validate_stream_terms(&terms)?;
+            },
+            ProposalKind::CancelStream(stream_id) => {
+                let stream = match self.data::<Data>().streams.get(&stream_id) {
+                    Some(stream) => stream,
+                    None => return Err(DaoError::StreamDoesNotExist),
+                };
+                if stream.status != StreamStatus::Active {
+                    return Err(DaoError::StreamNotActive)
+                }
+            },
+            _ => return Err(DaoError::InvalidProposalKind)
+        }
+
+        let proposal_id = self.create_proposal_internal(caller.clone(),description,duration,kind.clone());
+
+        if let ProposalKind::Expulsion(member) = kind {
+            self.data::<Data>().expulsions.insert(&member,&proposal_id);
+        }
+
+        self.emit_proposal_created_event(caller,proposal_id);
+
+        self.record_activity(caller.clone());
+
+        Ok(())
+    }
+
+    default fn apply_for_membership(&mut self, statement: String) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        if self.data::<Data>().members.contains(&caller) {
+            return Err(DaoError::MemberAlreadyExists)
+        }
+
+        if self.data::<Data>().applications.get(&caller).is_some() {
+            return Err(DaoError::ApplicationAlreadyExists)
+        }
+
+        let duration = self.data::<Data>().voting_period;
+
+        let proposal_id = self.create_proposal_internal(caller.clone(),statement,duration,ProposalKind::Membership(caller.clone()));
+
+        self.data::<Data>().applications.insert(&caller,&proposal_id);
 
         self.emit_proposal_created_event(caller,proposal_id);
 
@@ -150,6 +417,10 @@ where
             return Err(DaoError::MemberDoesNotExist)
         }
 
+        if self.is_expired(caller.clone()) {
+            return Err(DaoError::MembershipExpired)
+        }
+
         if self.data::<Data>().vote.get(&proposal_id).is_none() {
             return Err(DaoError::ProposalDoesNotExist);
         }
@@ -162,6 +433,10 @@ where
             return Err(DaoError::MemberHasAlreadyVoted)
         }
 
+        if vote.vote_status != VoteStatus::InProgress {
+            return Err(DaoError::VoteNotAvailable)
+        }
+
         let now = Self::env().block_timestamp();
 
         if now > vote.end {
@@ -174,8 +449,11 @@ where
             vote.no_votes += 1;
         }
 
+        self.data::<Data>().member_votes.insert(&(caller.clone(),proposal_id),&vote_cast);
         self.data::<Data>().vote.insert(&proposal_id.clone(),&vote);
 
+        self.record_activity(caller.clone());
+
         Ok(())
     }
 
@@ -202,22 +480,47 @@ where
             return Err(DaoError::VoteOngoing)
         }
 
-        if vote.yes_votes + vote.no_votes < self.data::<Data>().quorum {
-            vote.vote_status = VoteStatus::Failed;
-            self.data::<Data>().vote.insert(&proposal_id.clone(),&vote);
-        }
+        // Quorum is a percentage of the members who are neither expired nor inactive,
+        // on top of a minimum number of votes. Without active members nothing passes.
+
+        let votes = vote.yes_votes + vote.no_votes;
+        let active = self.active_member_count();
+        let turnout = votes as u64 * 100;
+        let required = self.data::<Data>().quorum as u64 * active as u64;
 
-        if vote.yes_votes > vote.no_votes {
+        if active > 0
+            && votes >= self.data::<Data>().min_turnout
+            && turnout >= required
+            && vote.yes_votes > vote.no_votes {
             vote.vote_status = VoteStatus::Passed;
-            self.data::<Data>().vote.insert(&proposal_id.clone(),&vote);
         } else {
             vote.vote_status = VoteStatus::Failed;
-            self.data::<Data>().vote.insert(&proposal_id.clone(),&vote);
         }
 
+        self.data::<Data>().vote.insert(&proposal_id.clone(),&vote);
+
+        if vote.vote_status == VoteStatus::Passed {
+            self.execute_proposal(proposal_id);
+        }
+
+        let kind = self.get_proposal_kind(proposal_id);
+
+        if let ProposalKind::Membership(applicant) = kind.clone() {
+            self.data::<Data>().applications.remove(&applicant);
+        }
+
+        if let ProposalKind::Expulsion(member) = kind {
+            if self.data::<Data>().expulsions.get(&member) == Some(proposal_id) {
+                self.data::<Data>().expulsions.remove(&member);
+            }
+        }
+
+        self.record_activity(caller.clone());
+
         Ok(())
     }
 
+    #[modifiers(only_role(PROJECT_LEAD))]
     default fn create_project(&mut self, description: String) -> Result<(),DaoError> {
         let caller = Self::env().caller();
 
@@ -238,6 +541,8 @@ where
 
         self.emit_project_created_event(caller,project_id);
 
+        self.record_activity(caller.clone());
+
         Ok(())
     }
 
@@ -261,6 +566,18 @@ where
             self.data::<Data>().project_members.insert(&project_id, &members);
         }
 
+        let member_projects = self.data::<Data>().member_projects.get(&caller);
+
+        if let Some(mut projects) = member_projects {
+            projects.push(project_id.clone());
+            self.data::<Data>().member_projects.insert(&caller, &projects);
+        } else {
+            let projects = vec![project_id.clone()];
+            self.data::<Data>().member_projects.insert(&caller, &projects);
+        }
+
+        self.record_activity(caller.clone());
+
         Ok(())
     }
 
@@ -271,6 +588,18 @@ where
             return Err(DaoError::MemberDoesNotExist)
         }
 
+        if self.is_expired(caller.clone()) {
+            return Err(DaoError::MembershipExpired)
+        }
+
+        if !self.data::<Data>().has_role(REVIEWER,reviewer.clone()) {
+            return Err(DaoError::MissingRole)
+        }
+
+        if reviewer == assignee {
+            return Err(DaoError::SelfReview)
+        }
+
         let now = Self::env().block_timestamp();
 
         let task_priority = match priority {
@@ -283,7 +612,7 @@ where
         let task = Task {
             description: description,
             assignee: assignee.clone(),
-            reviewer: reviewer,
+            reviewer: reviewer.clone(),
             owner: caller.clone(),
             deadline: now + duration,
             points: points,
@@ -304,6 +633,16 @@ where
             self.data::<Data>().member_tasks.insert(&assignee, &tasks);
         }
 
+        let reviewer_tasks = self.data::<Data>().reviewer_tasks.get(&reviewer);
+
+        if let Some(mut tasks) = reviewer_tasks {
+            tasks.push(task_id.clone());
+            self.data::<Data>().reviewer_tasks.insert(&reviewer, &tasks);
+        } else {
+            let tasks = vec![task_id.clone()];
+            self.data::<Data>().reviewer_tasks.insert(&reviewer, &tasks);
+        }
+
         self.data::<Data>().task.insert(&task_id.clone(),&task);
 
 
@@ -311,6 +650,8 @@ where
 
         self.emit_task_created_event(caller,task_id);
 
+        self.record_activity(caller.clone());
+
         Ok(())
     }
 
@@ -322,6 +663,10 @@ where
             return Err(DaoError::NotAProjectMember)
         }
 
+        if self.is_expired(caller.clone()) {
+            return Err(DaoError::MembershipExpired)
+        }
+
         if !self.is_project_member(project_id.clone(),assignee.clone()) {
             return Err(DaoError::NotAProjectMember)
         }
@@ -330,6 +675,14 @@ where
             return Err(DaoError::NotAProjectMember)
         }
 
+        if !self.data::<Data>().has_role(REVIEWER,reviewer.clone()) {
+            return Err(DaoError::MissingRole)
+        }
+
+        if reviewer == assignee {
+            return Err(DaoError::SelfReview)
+        }
+
         let now = Self::env().block_timestamp();
 
         let deadline = now + duration;
@@ -343,6 +696,8 @@ where
 
         let task_id = self.create_task_internal(description,caller.clone(),assignee.clone(),reviewer.clone(),deadline,task_priority,points);
 
+        self.data::<Data>().task_project.insert(&task_id,&project_id);
+
         let project_tasks = self.data::<Data>().project_tasks.get(&project_id);
 
         if let Some(mut tasks) = project_tasks {
@@ -365,6 +720,8 @@ where
 
         self.emit_task_created_event(caller,task_id);
 
+        self.record_activity(caller.clone());
+
         Ok(())
     }
 
@@ -385,6 +742,8 @@ where
 
         self.data::<Data>().task.insert(&task_id,&task);
 
+        self.record_activity(caller.clone());
+
         Ok(())
     }
 
@@ -405,6 +764,8 @@ where
 
         self.data::<Data>().task.insert(&task_id,&task);
 
+        self.record_activity(caller.clone());
+
         Ok(())
     }
 
@@ -421,23 +782,130 @@ where
             return Err(DaoError::IneligibleCaller)
         }
 
+        if task.assignee == caller {
+            return Err(DaoError::SelfReview)
+        }
+
+        if task.status != TaskStatus::UnderReview {
+            return Err(DaoError::TaskNotUnderReview)
+        }
+
+        if awarded_points > task.points {
+            return Err(DaoError::TooManyPoints)
+        }
+
+        if task.priority == TaskPriority::High
+            && self.member_tier_level(caller.clone()) < self.data::<Data>().tier_requirements.review_high_priority {
+            return Err(DaoError::TierTooLow)
+        }
+
+        // The task is closed before any point is minted so that it cannot be reviewed twice
+
         task.status = TaskStatus::Done;
         task.review = review;
+        self.data::<Data>().task.insert(&task_id,&task);
+        self.data::<Data>().task_awarded_points.insert(&task_id,&awarded_points);
 
         let assignee = task.assignee;
 
-        let member_points = self.data::<Data>().member_points.get(&assignee);
+        self.mint_points(assignee.clone(),awarded_points);
+        self.add_lifetime_points(assignee.clone(),awarded_points);
+        self.add_season_points(assignee.clone(),awarded_points);
 
-        if let Some(mut points) = member_points {
-            points = points + awarded_points;
-            self.data::<Data>().member_points.insert(&assignee, &points);
-        } else {
-            let points = awarded_points;
-            self.data::<Data>().member_points.insert(&assignee, &points);
+        let project = self.data::<Data>().task_project.get(&task_id);
+        self.record_award(assignee.clone(),project,awarded_points);
+
+        self.refresh_tier(assignee.clone());
+
+        let completed = self.data::<Data>().completed_tasks.get(&assignee).unwrap_or(0);
+        self.data::<Data>().completed_tasks.insert(&assignee,&completed.saturating_add(1));
+
+        if task.priority == TaskPriority::High {
+            let completed = self.data::<Data>().completed_high_priority.get(&assignee).unwrap_or(0);
+            self.data::<Data>().completed_high_priority.insert(&assignee,&completed.saturating_add(1));
+        }
+
+        self.check_badges(assignee.clone());
+
+        // A failed mint does not block the review, the assignee can claim the certificate later
+
+        if self.data::<Data>().certificate_contract.is_some() {
+            let _ = self.mint_certificate(task_id);
+        }
+
+        self.record_activity(caller.clone());
+
+        Ok(())
+    }
+
+    default fn reassign_task(&mut self, task_id: TaskId, assignee: AccountId, reviewer: AccountId) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        let mut task = match self.data::<Data>().task.get(&task_id) {
+            Some(task) => task,
+            None => return Err(DaoError::TaskDoesNotExist),
+        };
+
+        if task.owner != caller && !self.data::<Data>().has_role(PROJECT_LEAD,caller.clone()) {
+            return Err(DaoError::IneligibleCaller)
+        }
+
+        if task.status == TaskStatus::Done {
+            return Err(DaoError::TaskAlreadyDone)
+        }
+
+        if !self.data::<Data>().members.contains(&assignee) {
+            return Err(DaoError::MemberDoesNotExist)
+        }
+
+        if !self.data::<Data>().has_role(REVIEWER,reviewer.clone()) {
+            return Err(DaoError::MissingRole)
+        }
+
+        if reviewer == assignee {
+            return Err(DaoError::SelfReview)
+        }
+
+        if let Some(project_id) = self.data::<Data>().task_project.get(&task_id) {
+            if !self.is_project_member(project_id.clone(),assignee.clone())
+                || !self.is_project_member(project_id,reviewer.clone()) {
+                return Err(DaoError::NotAProjectMember)
+            }
+        }
+
+        // A new assignee starts the task over
+
+        if task.assignee != assignee {
+            if let Some(mut tasks) = self.data::<Data>().member_tasks.get(&task.assignee) {
+                tasks.retain(|id| *id != task_id);
+                self.data::<Data>().member_tasks.insert(&task.assignee, &tasks);
+            }
+
+            let mut tasks = self.data::<Data>().member_tasks.get(&assignee).unwrap_or_default();
+            tasks.push(task_id);
+            self.data::<Data>().member_tasks.insert(&assignee, &tasks);
+
+            task.assignee = assignee;
+            task.status = TaskStatus::ToDo;
+        }
+
+        if task.reviewer != reviewer {
+            if let Some(mut tasks) = self.data::<Data>().reviewer_tasks.get(&task.reviewer) {
+                tasks.retain(|id| *id != task_id);
+                self.data::<Data>().reviewer_tasks.insert(&task.reviewer, &tasks);
+            }
+
+            let mut tasks = self.data::<Data>().reviewer_tasks.get(&reviewer).unwrap_or_default();
+            tasks.push(task_id);
+            self.data::<Data>().reviewer_tasks.insert(&reviewer, &tasks);
+
+            task.reviewer = reviewer;
         }
 
         self.data::<Data>().task.insert(&task_id,&task);
 
+        self.record_activity(caller.clone());
+
         Ok(())
     }
 
@@ -449,8 +917,12 @@ where
         self.data::<Data>().quorum
     }
 
+    default fn get_min_turnout(&self) -> u32 {
+        self.data::<Data>().min_turnout
+    }
+
     default fn get_number_of_members(&self) -> u32 {
-        self.data::<Data>().member_id
+        self.data::<Data>().members.len() as u32
     }
 
     default fn get_members(&self) -> Vec<AccountId> {
@@ -554,13 +1026,7 @@ where
     }
 
     default fn get_member_points(&self, assignee: AccountId) -> u32 {
-        let member_points = self.data::<Data>().member_points.get(&assignee);
-
-        if let Some(points) = member_points {
-            return points;
-        } else {
-            return 0;
-        }
+        self.points_of(assignee)
     }
 
     default fn get_member_task_ids(&self, assignee:AccountId) -> Vec<TaskId> {
@@ -617,6 +1083,10 @@ where
         }
     }
 
+    default fn get_proposal_kind(&self,proposal_id: ProposalId) -> ProposalKind {
+        self.data::<Data>().proposal_kind.get(&proposal_id).unwrap_or(ProposalKind::General)
+    }
+
     default fn get_number_of_project_tasks(&self,project_id: ProjectId) -> u32 {
         let vec1 = self.data::<Data>().project_tasks.get(&project_id);//.len().try_into().unwrap()
         match vec1 {
@@ -629,6 +1099,32 @@ where
         self.data::<Data>().proposal_id
     }
 
+    #[modifiers(only_role(ADMIN))]
+    default fn set_eligibility_policy(&mut self, policy: EligibilityPolicy) -> Result<(),DaoError> {
+        self.data::<Data>().eligibility = policy;
+        Ok(())
+    }
+
+    default fn get_eligibility_policy(&self) -> EligibilityPolicy {
+        self.data::<Data>().eligibility.clone()
+    }
+
+    default fn get_merkle_root(&self) -> Option<Hash> {
+        self.data::<Data>().allowlist_root
+    }
+
+    default fn get_member_token(&self, member: AccountId) -> Option<(AccountId,TokenId)> {
+        self.data::<Data>().member_nft.get(&member)
+    }
+
+    default fn get_voting_period(&self) -> Timestamp {
+        self.data::<Data>().voting_period
+    }
+
+    default fn get_membership_application(&self, applicant: AccountId) -> Option<ProposalId> {
+        self.data::<Data>().applications.get(&applicant)
+    }
+
 }
 
 impl<T> DaoEvents for T
@@ -642,6 +1138,33 @@ where
     ) {
     }
 
+    default fn emit_member_removed_event(
+        &self,
+        _member: AccountId,
+    ) {
+    }
+
+    default fn emit_vouch_cast_event(
+        &self,
+        _voucher: AccountId,
+        _candidate: AccountId,
+    ) {
+    }
+
+    default fn emit_member_invited_event(
+        &self,
+        _inviter: AccountId,
+        _member: AccountId,
+    ) {
+    }
+
+    default fn emit_membership_renewed_event(
+        &self,
+        _member: AccountId,
+        _expiry: Timestamp,
+    ) {
+    }
+
     default fn emit_project_created_event(
         &self,
         _member: AccountId,
@@ -662,17 +1185,199 @@ where
         _task_id: u32,
     ) {
     }
+
+    default fn emit_action_submitted_event(
+        &self,
+        _signer: AccountId,
+        _action_id: u32,
+    ) {
+    }
+
+    default fn emit_action_confirmed_event(
+        &self,
+        _signer: AccountId,
+        _action_id: u32,
+    ) {
+    }
+
+    default fn emit_action_executed_event(
+        &self,
+        _signer: AccountId,
+        _action_id: u32,
+    ) {
+    }
+
+    default fn emit_role_granted_event(
+        &self,
+        _role: RoleType,
+        _account: AccountId,
+    ) {
+    }
+
+    default fn emit_role_revoked_event(
+        &self,
+        _role: RoleType,
+        _account: AccountId,
+    ) {
+    }
+
+    default fn emit_tier_changed_event(
+        &self,
+        _member: AccountId,
+        _tier: u32,
+    ) {
+    }
+
+    default fn emit_profile_updated_event(
+        &self,
+        _member: AccountId,
+    ) {
+    }
+
+    default fn emit_kudos_given_event(
+        &self,
+        _sender: AccountId,
+        _recipient: AccountId,
+        _points: u32,
+        _message_hash: Hash,
+    ) {
+    }
+
+    default fn emit_points_transferred_event(
+        &self,
+        _sender: AccountId,
+        _recipient: AccountId,
+        _points: u32,
+    ) {
+    }
+
+    default fn emit_epoch_started_event(
+        &self,
+        _epoch_id: u32,
+    ) {
+    }
+
+    default fn emit_epoch_closed_event(
+        &self,
+        _epoch_id: u32,
+        _total_allocated: u32,
+    ) {
+    }
+
+    default fn emit_season_closed_event(
+        &self,
+        _season_id: u32,
+    ) {
+    }
+
+    default fn emit_badge_minted_event(
+        &self,
+        _member: AccountId,
+        _badge_id: u32,
+        _token_id: TokenId,
+    ) {
+    }
+
+    default fn emit_certificate_minted_event(
+        &self,
+        _member: AccountId,
+        _task_id: TaskId,
+        _token_id: TokenId,
+    ) {
+    }
+
+    default fn emit_points_transfer_event(
+        &self,
+        _from: Option<AccountId>,
+        _to: Option<AccountId>,
+        _value: Balance,
+    ) {
+    }
+
+    default fn emit_points_redeemed_event(
+        &self,
+        _member: AccountId,
+        _redemption_id: RedemptionId,
+        _points: u32,
+        _reward: Balance,
+    ) {
+    }
+
+    default fn emit_rewards_claimed_event(
+        &self,
+        _member: AccountId,
+        _redemption_id: RedemptionId,
+        _amount: Balance,
+    ) {
+    }
+
+    default fn emit_deposit_event(
+        &self,
+        _from: AccountId,
+        _amount: Balance,
+    ) {
+    }
+
+    default fn emit_treasury_spent_event(
+        &self,
+        _spend_id: SpendId,
+        _asset: Asset,
+        _beneficiary: AccountId,
+        _amount: Balance,
+    ) {
+    }
+
+    default fn emit_tokens_recovered_event(
+        &self,
+        _token: AccountId,
+        _to: AccountId,
+        _amount: Balance,
+    ) {
+    }
+
+    default fn emit_stream_created_event(
+        &self,
+        _stream_id: StreamId,
+        _beneficiary: AccountId,
+    ) {
+    }
+
+    default fn emit_stream_withdrawn_event(
+        &self,
+        _stream_id: StreamId,
+        _beneficiary: AccountId,
+        _amount: Balance,
+    ) {
+    }
+
+    default fn emit_stream_cancelled_event(
+        &self,
+        _stream_id: StreamId,
+        _settled: Balance,
+    ) {
+    }
+
+    default fn emit_epoch_payout_claimed_event(
+        &self,
+        _member: AccountId,
+        _epoch_id: u32,
+        _amount: Balance,
+    ) {
+    }
 }
 
 impl<T> Internal for T
 where
     T: Storage<Data>,
 {
-    default fn is_eligible(&self,account: AccountId) -> bool {
-        let address = self.data::<Data>().token;
-        let balance = PSP34Ref::balance_of(&address, account.clone());
+    default fn is_eligible(&self,account: AccountId,credential: &Credential) -> bool {
+        self.data::<Data>().eligibility.is_satisfied(account,credential)
+    }
+
+    default fn owns_token(&self,account: AccountId,collection: AccountId,token_id: TokenId) -> bool {
+        let owner = PSP34Ref::owner_of(&collection, token_id);
 
-        if balance > 0 {
+        if owner == Some(account) {
             return true;
         } else {
             return false;
@@ -695,13 +1400,288 @@ where
         }
     }
 
+    default fn add_member_internal(&mut self,address: AccountId) -> u32 {
+        let member_id = self.data::<Data>().member_id.saturating_add(1);
+        self.data::<Data>().member_id = member_id;
+
+        self.data::<Data>().members.push(address.clone());
+
+        let now = Self::env().block_timestamp();
+        let duration = self.data::<Data>().membership_terms.duration;
+
+        if duration > 0 {
+            let expiry = now + duration;
+            self.data::<Data>().member_expiry.insert(&address,&expiry);
+        }
+
+        self.data::<Data>().last_activity.insert(&address,&now);
+
+        self.check_badges(address.clone());
+
+        member_id
+    }
+
+    default fn is_inactive(&self,account: AccountId) -> bool {
+        let period = self.data::<Data>().inactivity_period;
+
+        if period == 0 {
+            return false;
+        }
+
+        let last_activity = self.data::<Data>().last_activity.get(&account).unwrap_or(0);
+
+        Self::env().block_timestamp() >= last_activity + period
+    }
+
+    default fn record_activity(&mut self,account: AccountId) {
+        let now = Self::env().block_timestamp();
+        self.data::<Data>().last_activity.insert(&account,&now);
+    }
+
+    default fn active_member_count(&self) -> u32 {
+        let members = self.data::<Data>().members.clone();
+        let active = members.into_iter().filter(|member| !self.is_expired(member.clone()) && !self.is_inactive(member.clone())).count();
+
+        active as u32
+    }
+
+    default fn is_expired(&self,account: AccountId) -> bool {
+        // Expiries recorded while memberships expired no longer apply once they don't
+
+        if self.data::<Data>().membership_terms.duration == 0 {
+            return false;
+        }
+
+        let member_expiry = self.data::<Data>().member_expiry.get(&account);
+
+        if let Some(expiry) = member_expiry {
+            return Self::env().block_timestamp() >= expiry;
+        } else {
+            return false;
+        }
+    }
+
+    default fn create_proposal_internal(&mut self,creator: AccountId,description: String,duration: Timestamp,kind: ProposalKind) -> ProposalId {
+        let now = Self::env().block_timestamp();
+
+        let vote = Vote {
+            yes_votes: 0,
+            no_votes: 0,
+            start: now,
+            end: now + duration,
+            vote_status: VoteStatus::InProgress,
+        };
+
+        let proposal_id = self.data::<Data>().proposal_id.saturating_add(1);
+
+        self.data::<Data>().proposal.insert(&proposal_id.clone(),
+            &Proposal {
+                creator: creator.clone(),
+                description: description,
+        });
+
+        self.data::<Data>().proposal_kind.insert(&proposal_id,&kind);
+
+        let member_proposals = self.data::<Data>().member_proposals.get(&creator);
+
+        if let Some(mut proposals) = member_proposals {
+            proposals.push(proposal_id.clone());
+            self.data::<Data>().member_proposals.insert(&creator, &proposals);
+        } else {
+            let proposals = vec![proposal_id.clone()];
+            self.data::<Data>().member_proposals.insert(&creator, &proposals);
+        }
+
+        self.data::<Data>().vote.insert(&proposal_id.clone(),&vote);
+
+        self.data::<Data>().proposal_id = proposal_id;
+
+        proposal_id
+    }
+
+    default fn execute_proposal(&mut self,proposal_id: ProposalId) {
+        match self.get_proposal_kind(proposal_id) {
+            ProposalKind::General => {},
+            ProposalKind::Membership(applicant) => {
+                if !self.data::<Data>().members.contains(&applicant) {
+                    let member_id = self.add_member_internal(applicant.clone());
+                    self.emit_member_added_event(applicant,member_id);
+                }
+            },
+            ProposalKind::Expulsion(member) => {
+                // Stakes kept at risk after the member left are slashed all the same
+                self.settle_vouches(member.clone(),true);
+                if self.data::<Data>().members.contains(&member) {
+                    self.remove_member_internal(member.clone());
+                    self.emit_member_removed_event(member);
+                }
+            },
+            ProposalKind::SetEligibilityPolicy(policy) => {
+                self.data::<Data>().eligibility = policy;
+            },
+            ProposalKind::SetMerkleRoot(root) => {
+                self.data::<Data>().allowlist_root = root;
+            },
+            ProposalKind::GrantRole(role,account) => {
+                if self.data::<Data>().members.contains(&account) {
+                    let _ = self.grant_role_internal(role,account);
+                }
+            },
+            ProposalKind::RevokeRole(role,account) => {
+                let _ = self.revoke_role_internal(role,account);
+            },
+            ProposalKind::SetBadgeContract(contract) => {
+                self.data::<Data>().badge_contract = contract;
+            },
+            ProposalKind::AddBadgeRule(rule) => {
+                self.add_badge_rule_internal(rule);
+            },
+            ProposalKind::RemoveBadgeRule(badge_id) => {
+                self.data::<Data>().badge_rules.remove(&badge_id);
+                self.data::<Data>().badge_rule_ids.retain(|id| *id != badge_id);
+            },
+            ProposalKind::OpenRedemption(terms) => {
+                if self.validate_redemption_terms(&terms).is_ok() {
+                    self.start_redemption(terms);
+                }
+            },
+            ProposalKind::TreasurySpend(asset,beneficiary,amount) => {
+                self.spend(proposal_id,asset,beneficiary,amount);
+            },
+            ProposalKind::CreateStream(terms) => {
+                if self.validate_stream_terms(&terms).is_ok() {
+                    self.create_stream(terms);
+                }
+            },
+            ProposalKind::CancelStream(stream_id) => {
+                self.cancel_stream(stream_id);
+            },
+        }
+    }
+
+    default fn remove_member_internal(&mut self,address: AccountId) {
+        self.data::<Data>().members.retain(|member| *member != address);
+
+        if let Some(token) = self.data::<Data>().member_nft.get(&address) {
+            self.data::<Data>().token_member.remove(&token);
+            self.data::<Data>().member_nft.remove(&address);
+        }
+
+        self.data::<Data>().member_token.remove(&address);
+
+        // Leave all the projects
+
+        let projects = self.data::<Data>().member_projects.get(&address).unwrap_or_default();
+
+        for project_id in projects.iter() {
+            let mut members = self.data::<Data>().project_members.get(project_id).unwrap_or_default();
+            members.retain(|member| *member != address);
+            self.data::<Data>().project_members.insert(project_id, &members);
+        }
+
+        self.data::<Data>().member_projects.remove(&address);
+        self.data::<Data>().member_expiry.remove(&address);
+        self.data::<Data>().last_activity.remove(&address);
+        self.data::<Data>().member_profile.remove(&address);
+        self.clear_roles(address.clone());
+
+        // Open tasks go back to their owner when the owner can take them over, otherwise
+        // they are orphaned until reassigned. Finished tasks stay in the history of the removed member.
+
+        let task_ids = self.data::<Data>().member_tasks.get(&address).unwrap_or_default();
+        let mut history = Vec::new();
+
+        for task_id in task_ids.into_iter() {
+            let mut task = match self.data::<Data>().task.get(&task_id) {
+                Some(task) => task,
+                None => continue,
+            };
+
+            if task.status == TaskStatus::Done || task.assignee != address {
+                history.push(task_id);
+                continue;
+            }
+
+            let assignee = self.fallback_assignee(&task,address.clone());
+
+            task.assignee = assignee.clone();
+            task.status = TaskStatus::ToDo;
+            self.data::<Data>().task.insert(&task_id,&task);
+
+            if assignee != AccountId::from(ZERO_ADDRESS) {
+                let mut tasks = self.data::<Data>().member_tasks.get(&assignee).unwrap_or_default();
+                tasks.push(task_id);
+                self.data::<Data>().member_tasks.insert(&assignee, &tasks);
+            }
+        }
+
+        self.data::<Data>().member_tasks.insert(&address, &history);
+
+        let review_ids = self.data::<Data>().reviewer_tasks.get(&address).unwrap_or_default();
+        let mut reviewed = Vec::new();
+
+        for task_id in review_ids.into_iter() {
+            let mut task = match self.data::<Data>().task.get(&task_id) {
+                Some(task) => task,
+                None => continue,
+            };
+
+            if task.status == TaskStatus::Done || task.reviewer != address {
+                reviewed.push(task_id);
+                continue;
+            }
+
+            let reviewer = self.fallback_reviewer(&task,address.clone());
+
+            task.reviewer = reviewer.clone();
+            self.data::<Data>().task.insert(&task_id,&task);
+
+            if reviewer != AccountId::from(ZERO_ADDRESS) {
+                let mut tasks = self.data::<Data>().reviewer_tasks.get(&reviewer).unwrap_or_default();
+                tasks.push(task_id);
+                self.data::<Data>().reviewer_tasks.insert(&reviewer, &tasks);
+            }
+        }
+
+        self.data::<Data>().reviewer_tasks.insert(&address, &reviewed);
+
+        // Streams stop paying, what has accrued so far is still paid out
+
+        let stream_ids = self.data::<Data>().member_streams.get(&address).unwrap_or_default();
+
+        for stream_id in stream_ids.into_iter() {
+            self.cancel_stream(stream_id);
+        }
+    }
+
+    default fn fallback_assignee(&self,task: &Task,removed: AccountId) -> AccountId {
+        if task.owner != removed
+            && task.owner != task.reviewer
+            && self.data::<Data>().members.contains(&task.owner) {
+            task.owner.clone()
+        } else {
+            ZERO_ADDRESS.into()
+        }
+    }
+
+    default fn fallback_reviewer(&self,task: &Task,removed: AccountId) -> AccountId {
+        if task.owner != removed
+            && task.owner != task.assignee
+            && self.data::<Data>().members.contains(&task.owner)
+            && self.data::<Data>().has_role(REVIEWER,task.owner.clone()) {
+            task.owner.clone()
+        } else {
+            ZERO_ADDRESS.into()
+        }
+    }
+
     default fn create_task_internal(&mut self,description: String, caller: AccountId,assignee: AccountId, reviewer: AccountId, deadline: Timestamp,
          task_priority: TaskPriority, points: u32) -> TaskId {
         
         let task = Task {
             description: description,
             assignee: assignee,
-            reviewer: reviewer,
+            reviewer: reviewer.clone(),
             owner: caller.clone(),
             deadline: deadline,
             points: points,
@@ -714,6 +1694,15 @@ where
 
         self.data::<Data>().task.insert(&task_id.clone(),&task);
 
+        let reviewer_tasks = self.data::<Data>().reviewer_tasks.get(&reviewer);
+
+        if let Some(mut tasks) = reviewer_tasks {
+            tasks.push(task_id.clone());
+            self.data::<Data>().reviewer_tasks.insert(&reviewer, &tasks);
+        } else {
+            let tasks = vec![task_id.clone()];
+            self.data::<Data>().reviewer_tasks.insert(&reviewer, &tasks);
+        }
 
         self.data::<Data>().task_id = task_id;
 
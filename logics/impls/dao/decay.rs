@@ -0,0 +1,134 @@
+use crate::{
+    impls::dao::types::{
+        Data,
+        DaoError,
+        DecayModel,
+        ADMIN,
+    },
+    impls::dao::roles::only_role,
+    impls::dao::dao::DaoEvents,
+    traits::decay::DaoDecay,
+};
+
+use openbrush::{
+    modifiers,
+    traits::{
+        AccountId,
+        Balance,
+        DefaultEnv,
+        Storage,
+        Timestamp,
+    },
+};
+
+pub trait DecayInternal {
+    /// Points of `account` with the decay since their last change applied.
+    fn points_of(&self,account: AccountId) -> u32;
+
+    /// Stores the decayed points of `account`, to be called before changing them.
+    fn apply_decay(&mut self,account: AccountId);
+
+    fn add_lifetime_points(&mut self,account: AccountId,points: u32);
+}
+
+/// Returns the decayed points and the time consumed by the full periods elapsed.
+fn decay(model: &DecayModel, points: u32, elapsed: Timestamp) -> (u32,Timestamp) {
+    match model {
+        DecayModel::None => (points,0),
+        DecayModel::HalfLife(period) => {
+            let periods = elapsed / period;
+            let decayed = if periods >= 32 { 0 } else { points >> periods };
+            (decayed,periods * period)
+        },
+        DecayModel::Linear { period, amount } => {
+            let periods = elapsed / period;
+            let lost = u32::try_from(periods).unwrap_or(u32::MAX).saturating_mul(*amount);
+            (points.saturating_sub(lost),periods * period)
+        },
+    }
+}
+
+impl<T> DaoDecay for T
+where
+    T: Storage<Data>,
+{
+    #[modifiers(only_role(ADMIN))]
+    default fn set_decay_model(&mut self, model: DecayModel) -> Result<(),DaoError> {
+        match model {
+            DecayModel::HalfLife(0) | DecayModel::Linear { period: 0, .. } => return Err(DaoError::InvalidDecayModel),
+            _ => {},
+        }
+
+        // Decay under the previous model is settled, the new one starts counting now
+
+        let members = self.data::<Data>().members.clone();
+        let now = Self::env().block_timestamp();
+
+        for member in members.iter() {
+            self.apply_decay(member.clone());
+        }
+
+        self.data::<Data>().decay_model = model;
+
+        for member in members.iter() {
+            self.data::<Data>().points_updated.insert(member,&now);
+        }
+
+        Ok(())
+    }
+
+    default fn get_decay_model(&self) -> DecayModel {
+        self.data::<Data>().decay_model.clone()
+    }
+
+    default fn get_lifetime_points(&self, member: AccountId) -> u32 {
+        self.data::<Data>().lifetime_points.get(&member).unwrap_or(0)
+    }
+}
+
+impl<T> DecayInternal for T
+where
+    T: Storage<Data>,
+{
+    default fn points_of(&self,account: AccountId) -> u32 {
+        let points = self.data::<Data>().member_points.get(&account).unwrap_or(0);
+        let now = Self::env().block_timestamp();
+        let updated = self.data::<Data>().points_updated.get(&account).unwrap_or(now);
+
+        decay(&self.data::<Data>().decay_model,points,now.saturating_sub(updated)).0
+    }
+
+    default fn apply_decay(&mut self,account: AccountId) {
+        let now = Self::env().block_timestamp();
+
+        // Balances start decaying from the first time they are written
+
+        let last_update = self.data::<Data>().points_updated.get(&account);
+
+        if last_update.is_none() || self.data::<Data>().decay_model == DecayModel::None {
+            self.data::<Data>().points_updated.insert(&account,&now);
+            return
+        }
+
+        let updated = last_update.unwrap();
+
+        // Only full periods are consumed so that partial ones keep counting
+
+        let points = self.data::<Data>().member_points.get(&account).unwrap_or(0);
+        let (decayed,consumed) = decay(&self.data::<Data>().decay_model,points,now.saturating_sub(updated));
+
+        self.data::<Data>().member_points.insert(&account,&decayed);
+        self.data::<Data>().points_updated.insert(&account,&(updated + consumed));
+
+        if decayed < points {
+            let supply = self.data::<Data>().points_supply;
+            self.data::<Data>().points_supply = supply.saturating_sub((points - decayed) as Balance);
+            self.emit_points_transfer_event(Some(account),None,(points - decayed) as Balance);
+        }
+    }
+
+    default fn add_lifetime_points(&mut self,account: AccountId,points: u32) {
+        let lifetime = self.data::<Data>().lifetime_points.get(&account).unwrap_or(0);
+        self.data::<Data>().lifetime_points.insert(&account,&lifetime.saturating_add(points));
+    }
+}
@@ -0,0 +1,274 @@
+use crate::{
+    impls::dao::types::{
+        Data,
+        DaoError,
+        EpochId,
+        EpochReward,
+        EpochStatus,
+        GiveEpoch,
+        ADMIN,
+        TREASURER,
+    },
+    impls::dao::roles::only_role,
+    impls::dao::dao::{
+        DaoEvents,
+        Internal,
+    },
+    impls::dao::decay::DecayInternal,
+    impls::dao::leaderboard::LeaderboardInternal,
+    impls::dao::points_token::PointsInternal,
+    impls::dao::seasons::SeasonsInternal,
+    impls::dao::tiers::TiersInternal,
+    impls::dao::treasury::TreasuryInternal,
+    traits::epochs::DaoEpochs,
+};
+use ink::prelude::vec::Vec;
+
+use openbrush::{
+    modifiers,
+    traits::{
+        AccountId,
+        Balance,
+        DefaultEnv,
+        Storage,
+        Timestamp,
+    },
+};
+
+impl<T> DaoEpochs for T
+where
+    T: Storage<Data>,
+{
+    #[modifiers(only_role(ADMIN))]
+    default fn start_epoch(&mut self, duration: Timestamp, budget: u32, reward: EpochReward) -> Result<(),DaoError> {
+        if self.data::<Data>().current_epoch.is_some() {
+            return Err(DaoError::EpochAlreadyOpen)
+        }
+
+        if duration == 0 || budget == 0 {
+            return Err(DaoError::InvalidEpoch)
+        }
+
+        // Paying out of the treasury also needs the Treasurer role, the payout is set aside until claimed
+
+        if let EpochReward::PayoutShare(asset,amount) = &reward {
+            if !self.data::<Data>().has_role(TREASURER,Self::env().caller()) {
+                return Err(DaoError::MissingRole)
+            }
+            self.validate_spend(asset,*amount)?;
+            self.reserve_funds(asset,*amount);
+        }
+
+        let members = self.data::<Data>().members.clone();
+        let participants: Vec<AccountId> = members.into_iter()
+            .filter(|member| !self.is_expired(member.clone()) && !self.is_inactive(member.clone()))
+            .collect();
+
+        let now = Self::env().block_timestamp();
+        let epoch_id = self.data::<Data>().epoch_id.saturating_add(1);
+
+        self.data::<Data>().give_epoch.insert(&epoch_id,
+            &GiveEpoch {
+                start: now,
+                end: now + duration,
+                budget: budget,
+                reward: reward,
+                participants: participants,
+                total_allocated: 0,
+                status: EpochStatus::Open,
+        });
+
+        self.data::<Data>().epoch_id = epoch_id;
+        self.data::<Data>().current_epoch = Some(epoch_id);
+
+        self.emit_epoch_started_event(epoch_id);
+
+        Ok(())
+    }
+
+    default fn allocate(&mut self, recipient: AccountId, amount: u32) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        let epoch_id = match self.data::<Data>().current_epoch {
+            Some(epoch_id) => epoch_id,
+            None => return Err(DaoError::NoOpenEpoch),
+        };
+
+        let mut epoch = self.data::<Data>().give_epoch.get(&epoch_id).unwrap();
+
+        if Self::env().block_timestamp() >= epoch.end {
+            return Err(DaoError::EpochEnded)
+        }
+
+        // Participants who left or were expelled during the epoch cannot give anymore
+
+        if !self.data::<Data>().members.contains(&caller) {
+            return Err(DaoError::MemberDoesNotExist)
+        }
+
+        if !epoch.participants.contains(&caller) || !epoch.participants.contains(&recipient) {
+            return Err(DaoError::NotAParticipant)
+        }
+
+        if caller == recipient {
+            return Err(DaoError::SelfTransfer)
+        }
+
+        // A new allocation to the same colleague replaces the previous one
+
+        let previous = self.data::<Data>().epoch_allocation.get(&(epoch_id,caller.clone(),recipient.clone())).unwrap_or(0);
+        let given = self.data::<Data>().epoch_given.get(&(epoch_id,caller.clone())).unwrap_or(0) - previous + amount;
+
+        if given > epoch.budget {
+            return Err(DaoError::BudgetExceeded)
+        }
+
+        let received = self.data::<Data>().epoch_received.get(&(epoch_id,recipient.clone())).unwrap_or(0) - previous + amount;
+
+        self.data::<Data>().epoch_allocation.insert(&(epoch_id,caller.clone(),recipient.clone()),&amount);
+        self.data::<Data>().epoch_given.insert(&(epoch_id,caller.clone()),&given);
+        self.data::<Data>().epoch_received.insert(&(epoch_id,recipient.clone()),&received);
+
+        epoch.total_allocated = epoch.total_allocated - previous + amount;
+        self.data::<Data>().give_epoch.insert(&epoch_id,&epoch);
+
+        self.record_activity(caller.clone());
+
+        Ok(())
+    }
+
+    default fn close_epoch(&mut self) -> Result<(),DaoError> {
+        let epoch_id = match self.data::<Data>().current_epoch {
+            Some(epoch_id) => epoch_id,
+            None => return Err(DaoError::NoOpenEpoch),
+        };
+
+        let mut epoch = self.data::<Data>().give_epoch.get(&epoch_id).unwrap();
+
+        if Self::env().block_timestamp() < epoch.end {
+            return Err(DaoError::EpochNotFinished)
+        }
+
+        // Unspent budget is simply forfeited
+
+        match epoch.reward.clone() {
+            EpochReward::Points => {
+                for participant in epoch.participants.iter() {
+                    let received = self.data::<Data>().epoch_received.get(&(epoch_id,participant.clone())).unwrap_or(0);
+
+                    if received == 0 || !self.data::<Data>().members.contains(participant) {
+                        continue
+                    }
+
+                    self.mint_points(participant.clone(),received);
+                    self.add_lifetime_points(participant.clone(),received);
+                    self.add_season_points(participant.clone(),received);
+
+                    self.record_award(participant.clone(),None,received);
+                    self.refresh_tier(participant.clone());
+                }
+            },
+            EpochReward::PayoutShare(asset,amount) => {
+                let mut shared: Balance = 0;
+
+                for participant in epoch.participants.iter() {
+                    let received = self.data::<Data>().epoch_received.get(&(epoch_id,participant.clone())).unwrap_or(0);
+
+                    if received == 0 || !self.data::<Data>().members.contains(participant) {
+                        continue
+                    }
+
+                    let share = amount.saturating_mul(received as Balance) / epoch.total_allocated as Balance;
+                    self.data::<Data>().epoch_payouts.insert(&(epoch_id,participant.clone()),&share);
+                    shared += share;
+                }
+
+                // Shares of members who left and rounding leftovers go back to the treasury
+
+                self.release_funds(&asset,amount - shared);
+            },
+        }
+
+        epoch.status = EpochStatus::Closed;
+        self.data::<Data>().give_epoch.insert(&epoch_id,&epoch);
+        self.data::<Data>().current_epoch = None;
+
+        self.emit_epoch_closed_event(epoch_id,epoch.total_allocated);
+
+        Ok(())
+    }
+
+    default fn claim_epoch_payout(&mut self, epoch_id: EpochId) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        let asset = match self.data::<Data>().give_epoch.get(&epoch_id).map(|epoch| epoch.reward) {
+            Some(EpochReward::PayoutShare(asset,_)) => asset,
+            _ => return Err(DaoError::NothingToClaim),
+        };
+
+        let amount = self.data::<Data>().epoch_payouts.get(&(epoch_id,caller.clone())).unwrap_or(0);
+
+        if amount == 0 {
+            return Err(DaoError::NothingToClaim)
+        }
+
+        let payout = (epoch_id,caller.clone());
+
+        self.pay_reserved(&asset,caller.clone(),amount,|dao: &mut Self,paid| {
+            if paid {
+                dao.data::<Data>().epoch_payouts.remove(&payout);
+            } else {
+                dao.data::<Data>().epoch_payouts.insert(&payout,&amount);
+            }
+        }).map_err(|_| DaoError::RewardTransferFailed)?;
+
+        self.emit_epoch_payout_claimed_event(caller,epoch_id,amount);
+
+        Ok(())
+    }
+
+    default fn get_current_epoch(&self) -> Option<EpochId> {
+        self.data::<Data>().current_epoch
+    }
+
+    default fn get_epoch(&self, epoch_id: EpochId) -> Option<GiveEpoch> {
+        self.data::<Data>().give_epoch.get(&epoch_id)
+    }
+
+    default fn get_number_of_epochs(&self) -> u32 {
+        self.data::<Data>().epoch_id
+    }
+
+    default fn get_allocation(&self, epoch_id: EpochId, giver: AccountId, recipient: AccountId) -> u32 {
+        self.data::<Data>().epoch_allocation.get(&(epoch_id,giver,recipient)).unwrap_or(0)
+    }
+
+    default fn get_epoch_budget_left(&self, epoch_id: EpochId, member: AccountId) -> u32 {
+        let epoch = match self.data::<Data>().give_epoch.get(&epoch_id) {
+            Some(epoch) => epoch,
+            None => return 0,
+        };
+
+        if epoch.status != EpochStatus::Open || !epoch.participants.contains(&member) {
+            return 0
+        }
+
+        epoch.budget - self.data::<Data>().epoch_given.get(&(epoch_id,member)).unwrap_or(0)
+    }
+
+    default fn get_epoch_received(&self, epoch_id: EpochId, member: AccountId) -> u32 {
+        self.data::<Data>().epoch_received.get(&(epoch_id,member)).unwrap_or(0)
+    }
+
+    default fn get_epoch_results(&self, epoch_id: EpochId) -> Vec<(AccountId,u32)> {
+        let participants = self.data::<Data>().give_epoch.get(&epoch_id).map(|epoch| epoch.participants).unwrap_or_default();
+
+        participants.into_iter()
+            .map(|participant| (participant.clone(),self.data::<Data>().epoch_received.get(&(epoch_id,participant)).unwrap_or(0)))
+            .collect()
+    }
+
+    default fn get_epoch_payout(&self, epoch_id: EpochId, member: AccountId) -> Balance {
+        self.data::<Data>().epoch_payouts.get(&(epoch_id,member)).unwrap_or(0)
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{
+    impls::dao::types::{
+        Data,
+        DaoError,
+        Invite,
+        MAX_INVITE_USES,
+    },
+    impls::dao::dao::{
+        DaoEvents,
+        Internal,
+    },
+    traits::invite::DaoInvite,
+};
+use ink::env::hash::Blake2x256;
+use ink::prelude::vec::Vec;
+use ink::primitives::Hash;
+
+use openbrush::{
+    traits::{
+        AccountId,
+        DefaultEnv,
+        Storage,
+        Timestamp,
+    },
+};
+
+/// Commitment of an invite: `blake2x256` of its secret.
+pub fn invite_commitment(secret: &[u8]) -> Hash {
+    let mut commitment = [0u8; 32];
+    ink::env::hash_bytes::<Blake2x256>(secret, &mut commitment);
+    Hash::from(commitment)
+}
+
+impl<T> DaoInvite for T
+where
+    T: Storage<Data>,
+{
+    default fn create_invite(&mut self, commitment: Hash, invitee: Option<AccountId>, expiry: Timestamp, max_uses: u32) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        if !self.data::<Data>().members.contains(&caller) {
+            return Err(DaoError::MemberDoesNotExist)
+        }
+
+        if self.is_expired(caller.clone()) {
+            return Err(DaoError::MembershipExpired)
+        }
+
+        if self.data::<Data>().invites.get(&commitment).is_some() {
+            return Err(DaoError::InviteAlreadyExists)
+        }
+
+        let now = Self::env().block_timestamp();
+
+        if expiry <= now || max_uses == 0 || max_uses > MAX_INVITE_USES {
+            return Err(DaoError::InvalidInvite)
+        }
+
+        self.data::<Data>().invites.insert(&commitment,
+            &Invite {
+                inviter: caller.clone(),
+                invitee: invitee,
+                expiry: expiry,
+                max_uses: max_uses,
+                uses: 0,
+        });
+
+        self.record_activity(caller.clone());
+
+        Ok(())
+    }
+
+    default fn revoke_invite(&mut self, commitment: Hash) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        let invite = match self.data::<Data>().invites.get(&commitment) {
+            Some(invite) => invite,
+            None => return Err(DaoError::InviteDoesNotExist),
+        };
+
+        if invite.inviter != caller {
+            return Err(DaoError::IneligibleCaller)
+        }
+
+        self.data::<Data>().invites.remove(&commitment);
+
+        Ok(())
+    }
+
+    default fn join_with_invite(&mut self, secret: Vec<u8>) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        if self.data::<Data>().members.contains(&caller) {
+            return Err(DaoError::MemberAlreadyExists)
+        }
+
+        let commitment = invite_commitment(&secret);
+
+        let mut invite = match self.data::<Data>().invites.get(&commitment) {
+            Some(invite) => invite,
+            None => return Err(DaoError::InviteDoesNotExist),
+        };
+
+        // A personal invite is bound to its invitee, so a secret seen in a
+        // pending transaction cannot be used from another account
+
+        if invite.invitee.is_some() && invite.invitee != Some(caller.clone()) {
+            return Err(DaoError::IneligibleCaller)
+        }
+
+        if Self::env().block_timestamp() >= invite.expiry {
+            return Err(DaoError::InviteExpired)
+        }
+
+        if invite.uses >= invite.max_uses {
+            return Err(DaoError::InviteExhausted)
+        }
+
+        if !self.data::<Data>().members.contains(&invite.inviter) || self.is_expired(invite.inviter.clone()) {
+            return Err(DaoError::InviterNotMember)
+        }
+
+        invite.uses += 1;
+        self.data::<Data>().invites.insert(&commitment,&invite);
+
+        let member_id = self.add_member_internal(caller.clone());
+
+        // Keep track of who brought whom in
+
+        let inviter = invite.inviter;
+
+        self.data::<Data>().invited_by.insert(&caller,&inviter);
+
+        let mut invitees = self.data::<Data>().member_invitees.get(&inviter).unwrap_or_default();
+        invitees.push(caller.clone());
+        self.data::<Data>().member_invitees.insert(&inviter,&invitees);
+
+        self.emit_member_added_event(caller.clone(),member_id);
+        self.emit_member_invited_event(inviter,caller);
+
+        Ok(())
+    }
+
+    default fn get_invite(&self, commitment: Hash) -> Option<Invite> {
+        self.data::<Data>().invites.get(&commitment)
+    }
+
+    default fn get_inviter(&self, member: AccountId) -> Option<AccountId> {
+        self.data::<Data>().invited_by.get(&member)
+    }
+
+    default fn get_invitees(&self, member: AccountId) -> Vec<AccountId> {
+        self.data::<Data>().member_invitees.get(&member).unwrap_or_default()
+    }
+}
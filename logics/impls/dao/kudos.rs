@@ -0,0 +1,149 @@
+use crate::{
+    impls::dao::types::{
+        Data,
+        DaoError,
+        KudosConfig,
+        ADMIN,
+    },
+    impls::dao::roles::only_role,
+    impls::dao::dao::{
+        DaoEvents,
+        Internal,
+    },
+    impls::dao::decay::DecayInternal,
+    impls::dao::leaderboard::LeaderboardInternal,
+    impls::dao::points_token::PointsInternal,
+    impls::dao::seasons::SeasonsInternal,
+    impls::dao::tiers::TiersInternal,
+    traits::kudos::DaoKudos,
+};
+use ink::primitives::Hash;
+
+use openbrush::{
+    modifiers,
+    traits::{
+        AccountId,
+        DefaultEnv,
+        Storage,
+    },
+};
+
+pub trait KudosInternal {
+    /// Current kudos epoch, `None` when kudos are disabled.
+    fn kudos_epoch(&self) -> Option<u64>;
+
+    /// Checks that points can move from `sender` to `recipient`.
+    fn check_peers(&self,sender: AccountId,recipient: AccountId) -> Result<(),DaoError>;
+}
+
+impl<T> DaoKudos for T
+where
+    T: Storage<Data>,
+{
+    default fn give_kudos(&mut self, recipient: AccountId, points: u32, message_hash: Hash) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        let epoch = match self.kudos_epoch() {
+            Some(epoch) => epoch,
+            None => return Err(DaoError::KudosDisabled),
+        };
+
+        self.check_peers(caller.clone(),recipient.clone())?;
+
+        // Kudos come from the allowance, the sender keeps their own points
+
+        let spent = self.data::<Data>().kudos_spent.get(&(caller.clone(),epoch)).unwrap_or(0);
+
+        if spent.saturating_add(points) > self.data::<Data>().kudos_config.allowance {
+            return Err(DaoError::KudosAllowanceExceeded)
+        }
+
+        self.data::<Data>().kudos_spent.insert(&(caller.clone(),epoch),&(spent + points));
+
+        self.mint_points(recipient.clone(),points);
+        self.add_lifetime_points(recipient.clone(),points);
+        self.add_season_points(recipient.clone(),points);
+
+        self.record_award(recipient.clone(),None,points);
+        self.refresh_tier(recipient.clone());
+
+        self.emit_kudos_given_event(caller.clone(),recipient,points,message_hash);
+
+        self.record_activity(caller.clone());
+
+        Ok(())
+    }
+
+    default fn transfer_points(&mut self, recipient: AccountId, points: u32) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        if !self.data::<Data>().kudos_config.transfers_enabled {
+            return Err(DaoError::PointTransfersDisabled)
+        }
+
+        self.check_peers(caller.clone(),recipient.clone())?;
+
+        self.move_points(caller.clone(),recipient.clone(),points)?;
+
+        self.refresh_tier(caller.clone());
+        self.refresh_tier(recipient.clone());
+
+        self.emit_points_transferred_event(caller.clone(),recipient,points);
+
+        self.record_activity(caller.clone());
+
+        Ok(())
+    }
+
+    #[modifiers(only_role(ADMIN))]
+    default fn set_kudos_config(&mut self, config: KudosConfig) -> Result<(),DaoError> {
+        self.data::<Data>().kudos_config = config;
+        Ok(())
+    }
+
+    default fn get_kudos_config(&self) -> KudosConfig {
+        self.data::<Data>().kudos_config.clone()
+    }
+
+    default fn get_kudos_left(&self, member: AccountId) -> u32 {
+        let epoch = match self.kudos_epoch() {
+            Some(epoch) => epoch,
+            None => return 0,
+        };
+
+        let spent = self.data::<Data>().kudos_spent.get(&(member,epoch)).unwrap_or(0);
+
+        self.data::<Data>().kudos_config.allowance.saturating_sub(spent)
+    }
+}
+
+impl<T> KudosInternal for T
+where
+    T: Storage<Data>,
+{
+    default fn kudos_epoch(&self) -> Option<u64> {
+        let epoch_length = self.data::<Data>().kudos_config.epoch_length;
+
+        if epoch_length == 0 {
+            return None
+        }
+
+        Some(Self::env().block_timestamp() / epoch_length)
+    }
+
+    default fn check_peers(&self,sender: AccountId,recipient: AccountId) -> Result<(),DaoError> {
+        if !self.data::<Data>().members.contains(&sender) || !self.data::<Data>().members.contains(&recipient) {
+            return Err(DaoError::MemberDoesNotExist)
+        }
+
+        if self.is_expired(sender.clone()) {
+            return Err(DaoError::MembershipExpired)
+        }
+
+        if sender == recipient {
+            return Err(DaoError::SelfTransfer)
+        }
+
+        Ok(())
+    }
+}
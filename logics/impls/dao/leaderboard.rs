@@ -0,0 +1,150 @@
+use crate::{
+    impls::dao::types::{
+        Data,
+        AwardId,
+        PointAward,
+        ProjectId,
+    },
+    impls::dao::decay::DecayInternal,
+    traits::leaderboard::DaoLeaderboard,
+};
+use ink::prelude::vec::Vec;
+
+use openbrush::{
+    traits::{
+        AccountId,
+        DefaultEnv,
+        Storage,
+        Timestamp,
+    },
+};
+
+pub trait LeaderboardInternal {
+    /// Logs points earned by `member`, crediting `project` when the task belonged to one.
+    fn record_award(&mut self,member: AccountId,project: Option<ProjectId>,points: u32);
+
+    /// Id of the first award logged at or after `timestamp`.
+    fn first_award_from(&self,timestamp: Timestamp) -> AwardId;
+}
+
+/// Sorts by points, highest first (ties keep their order), and returns one page.
+fn rank(mut entries: Vec<(AccountId,u32)>, offset: u32, limit: u32) -> Vec<(AccountId,u32)> {
+    entries.sort_by(|a, b| b.1.cmp(&a.1));
+    entries.into_iter().skip(offset as usize).take(limit as usize).collect()
+}
+
+impl<T> DaoLeaderboard for T
+where
+    T: Storage<Data>,
+{
+    default fn get_leaderboard(&self, offset: u32, limit: u32) -> Vec<(AccountId,u32)> {
+        let entries = self.data::<Data>().members.iter()
+            .map(|member| (member.clone(),self.points_of(member.clone())))
+            .collect();
+
+        rank(entries,offset,limit)
+    }
+
+    default fn get_member_rank(&self, member: AccountId) -> Option<u32> {
+        if !self.data::<Data>().members.contains(&member) {
+            return None
+        }
+
+        // Rank 1 is the top, members with equal points share a rank
+
+        let points = self.points_of(member.clone());
+        let ahead = self.data::<Data>().members.iter()
+            .filter(|other| self.points_of((*other).clone()) > points)
+            .count();
+
+        Some(ahead as u32 + 1)
+    }
+
+    default fn get_project_leaderboard(&self, project_id: ProjectId, offset: u32, limit: u32) -> Vec<(AccountId,u32)> {
+        let entries = self.data::<Data>().project_contributors.get(&project_id).unwrap_or_default().into_iter()
+            .filter(|member| self.data::<Data>().members.contains(member))
+            .map(|member| (member.clone(),self.data::<Data>().project_points.get(&(project_id,member)).unwrap_or(0)))
+            .collect();
+
+        rank(entries,offset,limit)
+    }
+
+    default fn get_project_points(&self, project_id: ProjectId, member: AccountId) -> u32 {
+        self.data::<Data>().project_points.get(&(project_id,member)).unwrap_or(0)
+    }
+
+    default fn get_leaderboard_between(&self, from: Timestamp, to: Timestamp, offset: u32, limit: u32) -> Vec<(AccountId,u32)> {
+        let mut entries: Vec<(AccountId,u32)> = Vec::new();
+        let last = self.data::<Data>().award_id;
+        let mut award_id = self.first_award_from(from);
+
+        while award_id <= last {
+            let award = self.data::<Data>().point_awards.get(&award_id).unwrap_or_default();
+
+            if award.timestamp >= to {
+                break
+            }
+
+            if self.data::<Data>().members.contains(&award.member) {
+                match entries.iter_mut().find(|entry| entry.0 == award.member) {
+                    Some(entry) => entry.1 = entry.1.saturating_add(award.points),
+                    None => entries.push((award.member,award.points)),
+                }
+            }
+
+            award_id += 1;
+        }
+
+        rank(entries,offset,limit)
+    }
+}
+
+impl<T> LeaderboardInternal for T
+where
+    T: Storage<Data>,
+{
+    default fn record_award(&mut self,member: AccountId,project: Option<ProjectId>,points: u32) {
+        let award_id = self.data::<Data>().award_id.saturating_add(1);
+
+        self.data::<Data>().point_awards.insert(&award_id,
+            &PointAward {
+                member: member.clone(),
+                project: project,
+                points: points,
+                timestamp: Self::env().block_timestamp(),
+        });
+
+        self.data::<Data>().award_id = award_id;
+
+        if let Some(project_id) = project {
+            let earned = self.data::<Data>().project_points.get(&(project_id,member.clone())).unwrap_or(0);
+            self.data::<Data>().project_points.insert(&(project_id,member.clone()),&earned.saturating_add(points));
+
+            let mut contributors = self.data::<Data>().project_contributors.get(&project_id).unwrap_or_default();
+            if !contributors.contains(&member) {
+                contributors.push(member);
+                self.data::<Data>().project_contributors.insert(&project_id,&contributors);
+            }
+        }
+    }
+
+    default fn first_award_from(&self,timestamp: Timestamp) -> AwardId {
+        // Awards are logged in block order, so their timestamps never decrease
+
+        let mut low = 1;
+        let mut high = self.data::<Data>().award_id + 1;
+
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let award = self.data::<Data>().point_awards.get(&mid).unwrap_or_default();
+
+            if award.timestamp < timestamp {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        low
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{
+    impls::dao::types::{
+        Data,
+        DaoError,
+        MembershipTerms,
+        ADMIN,
+        MEMBERSHIP_MANAGER,
+    },
+    impls::dao::roles::only_role,
+    impls::dao::dao::{
+        DaoEvents,
+        Internal,
+    },
+    traits::membership::DaoMembership,
+};
+use ink::prelude::vec::Vec;
+
+use openbrush::{
+    modifiers,
+    traits::{
+        AccountId,
+        DefaultEnv,
+        Storage,
+        Timestamp,
+    },
+};
+
+impl<T> DaoMembership for T
+where
+    T: Storage<Data>,
+{
+    default fn renew_membership(&mut self) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        if !self.data::<Data>().members.contains(&caller) {
+            return Err(DaoError::MemberDoesNotExist)
+        }
+
+        let terms = self.data::<Data>().membership_terms.clone();
+
+        if terms.duration == 0 {
+            return Err(DaoError::RenewalNotAvailable)
+        }
+
+        if Self::env().transferred_value() < terms.renewal_fee {
+            return Err(DaoError::InsufficientFee)
+        }
+
+        if terms.require_token {
+            if let Some((collection,token_id)) = self.data::<Data>().member_nft.get(&caller) {
+                if !self.owns_token(caller.clone(),collection,token_id) {
+                    return Err(DaoError::NotEligibleForMembership)
+                }
+            }
+        }
+
+        // Renewing early extends the current period instead of cutting it short
+
+        let now = Self::env().block_timestamp();
+        let current = self.data::<Data>().member_expiry.get(&caller).unwrap_or(now);
+        let expiry = current.max(now) + terms.duration;
+
+        self.data::<Data>().member_expiry.insert(&caller,&expiry);
+
+        self.emit_membership_renewed_event(caller,expiry);
+
+        self.record_activity(caller.clone());
+
+        Ok(())
+    }
+
+    #[modifiers(only_role(ADMIN))]
+    default fn set_membership_terms(&mut self, terms: MembershipTerms) -> Result<(),DaoError> {
+        self.data::<Data>().membership_terms = terms;
+        Ok(())
+    }
+
+    #[modifiers(only_role(MEMBERSHIP_MANAGER))]
+    default fn set_member_expiry(&mut self, member: AccountId, expiry: Option<Timestamp>) -> Result<(),DaoError> {
+        if !self.data::<Data>().members.contains(&member) {
+            return Err(DaoError::MemberDoesNotExist)
+        }
+
+        if let Some(expiry) = expiry {
+            self.data::<Data>().member_expiry.insert(&member,&expiry);
+        } else {
+            self.data::<Data>().member_expiry.remove(&member);
+        }
+
+        Ok(())
+    }
+
+    #[modifiers(only_role(ADMIN))]
+    default fn set_inactivity_period(&mut self, period: Timestamp) -> Result<(),DaoError> {
+        self.data::<Data>().inactivity_period = period;
+        Ok(())
+    }
+
+    default fn get_inactivity_period(&self) -> Timestamp {
+        self.data::<Data>().inactivity_period
+    }
+
+    default fn get_last_activity(&self, member: AccountId) -> Timestamp {
+        self.data::<Data>().last_activity.get(&member).unwrap_or(0)
+    }
+
+    default fn is_member_active(&self, member: AccountId) -> bool {
+        self.data::<Data>().members.contains(&member) && !self.is_expired(member.clone()) && !self.is_inactive(member.clone())
+    }
+
+    default fn get_number_of_active_members(&self) -> u32 {
+        self.active_member_count()
+    }
+
+    default fn get_membership_terms(&self) -> MembershipTerms {
+        self.data::<Data>().membership_terms.clone()
+    }
+
+    default fn get_member_expiry(&self, member: AccountId) -> Option<Timestamp> {
+        if self.data::<Data>().membership_terms.duration == 0 {
+            return None
+        }
+
+        self.data::<Data>().member_expiry.get(&member)
+    }
+
+    default fn get_members_expiring_before(&self, timestamp: Timestamp) -> Vec<AccountId> {
+        let mut expiring = Vec::new();
+
+        if self.data::<Data>().membership_terms.duration == 0 {
+            return expiring
+        }
+
+        for member in self.data::<Data>().members.iter() {
+            if let Some(expiry) = self.data::<Data>().member_expiry.get(member) {
+                if expiry <= timestamp {
+                    expiring.push(member.clone());
+                }
+            }
+        }
+
+        expiring
+    }
+}
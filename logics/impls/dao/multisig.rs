@@ -0,0 +1,272 @@
+use crate::{
+    impls::dao::types::{
+        Data,
+        DaoError,
+        ActionId,
+        ActionStatus,
+        AdminAction,
+        AdminProposal,
+        VoteStatus,
+    },
+    impls::dao::dao::{
+        DaoEvents,
+        Internal,
+    },
+    impls::dao::roles::RolesInternal,
+    traits::multisig::DaoMultisig,
+};
+use ink::prelude::vec::Vec;
+use ink::prelude::vec;
+
+use openbrush::{
+    traits::{
+        AccountId,
+        DefaultEnv,
+        Storage,
+    },
+};
+
+/// A threshold is valid when it can be reached by the signers and a single
+/// signer cannot act alone on behalf of several.
+pub fn valid_threshold(threshold: u32, signers: u32) -> bool {
+    threshold > 0 && threshold <= signers && (signers == 1 || threshold >= 2)
+}
+
+pub trait MultisigInternal {
+    fn is_signer(&self,account: AccountId) -> bool;
+
+    fn confirmation_count(&self,action: &AdminProposal) -> u32;
+
+    fn execute_admin_action(&mut self,action: AdminAction) -> Result<(),DaoError>;
+}
+
+impl<T> DaoMultisig for T
+where
+    T: Storage<Data>,
+{
+    default fn submit_action(&mut self, action: AdminAction) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        if !self.is_signer(caller.clone()) {
+            return Err(DaoError::NotASigner)
+        }
+
+        let action_id = self.data::<Data>().action_id.saturating_add(1);
+
+        // The proposer confirms the action when submitting it
+
+        self.data::<Data>().admin_action.insert(&action_id.clone(),
+            &AdminProposal {
+                proposer: caller.clone(),
+                action: action,
+                confirmations: vec![caller.clone()],
+                status: ActionStatus::Pending,
+        });
+
+        self.data::<Data>().action_id = action_id;
+
+        self.emit_action_submitted_event(caller,action_id);
+
+        Ok(())
+    }
+
+    default fn confirm_action(&mut self, action_id: ActionId) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        if !self.is_signer(caller.clone()) {
+            return Err(DaoError::NotASigner)
+        }
+
+        if self.data::<Data>().admin_action.get(&action_id).is_none() {
+            return Err(DaoError::ActionDoesNotExist)
+        }
+
+        let mut action = self.data::<Data>().admin_action.get(&action_id).unwrap();
+
+        if action.status != ActionStatus::Pending {
+            return Err(DaoError::ActionNotPending)
+        }
+
+        if action.confirmations.contains(&caller) {
+            return Err(DaoError::ActionAlreadyConfirmed)
+        }
+
+        action.confirmations.push(caller.clone());
+
+        self.data::<Data>().admin_action.insert(&action_id,&action);
+
+        self.emit_action_confirmed_event(caller,action_id);
+
+        Ok(())
+    }
+
+    default fn revoke_confirmation(&mut self, action_id: ActionId) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        if !self.is_signer(caller.clone()) {
+            return Err(DaoError::NotASigner)
+        }
+
+        if self.data::<Data>().admin_action.get(&action_id).is_none() {
+            return Err(DaoError::ActionDoesNotExist)
+        }
+
+        let mut action = self.data::<Data>().admin_action.get(&action_id).unwrap();
+
+        if action.status != ActionStatus::Pending {
+            return Err(DaoError::ActionNotPending)
+        }
+
+        if !action.confirmations.contains(&caller) {
+            return Err(DaoError::ActionNotConfirmed)
+        }
+
+        action.confirmations.retain(|signer| *signer != caller);
+
+        self.data::<Data>().admin_action.insert(&action_id,&action);
+
+        Ok(())
+    }
+
+    default fn execute_action(&mut self, action_id: ActionId) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        if !self.is_signer(caller.clone()) {
+            return Err(DaoError::NotASigner)
+        }
+
+        if self.data::<Data>().admin_action.get(&action_id).is_none() {
+            return Err(DaoError::ActionDoesNotExist)
+        }
+
+        let mut action = self.data::<Data>().admin_action.get(&action_id).unwrap();
+
+        if action.status != ActionStatus::Pending {
+            return Err(DaoError::ActionNotPending)
+        }
+
+        if self.confirmation_count(&action) < self.data::<Data>().threshold {
+            return Err(DaoError::NotEnoughConfirmations)
+        }
+
+        self.execute_admin_action(action.action.clone())?;
+
+        action.status = ActionStatus::Executed;
+
+        self.data::<Data>().admin_action.insert(&action_id,&action);
+
+        self.emit_action_executed_event(caller,action_id);
+
+        Ok(())
+    }
+
+    default fn get_signers(&self) -> Vec<AccountId> {
+        self.data::<Data>().signers.clone()
+    }
+
+    default fn get_threshold(&self) -> u32 {
+        self.data::<Data>().threshold
+    }
+
+    default fn get_admin_action(&self, action_id: ActionId) -> Option<AdminProposal> {
+        self.data::<Data>().admin_action.get(&action_id)
+    }
+
+    default fn get_number_of_actions(&self) -> u32 {
+        self.data::<Data>().action_id
+    }
+
+}
+
+impl<T> MultisigInternal for T
+where
+    T: Storage<Data>,
+{
+    default fn is_signer(&self,account: AccountId) -> bool {
+        self.data::<Data>().signers.contains(&account)
+    }
+
+    default fn confirmation_count(&self,action: &AdminProposal) -> u32 {
+        // Confirmations of signers removed since they confirmed no longer count
+
+        let signers = &self.data::<Data>().signers;
+        let count = action.confirmations.iter().filter(|signer| signers.contains(signer)).count();
+
+        count as u32
+    }
+
+    default fn execute_admin_action(&mut self,action: AdminAction) -> Result<(),DaoError> {
+        match action {
+            AdminAction::AddMember(address) => {
+                if self.data::<Data>().members.contains(&address) {
+                    return Err(DaoError::MemberAlreadyExists)
+                }
+                let member_id = self.add_member_internal(address.clone());
+                self.emit_member_added_event(address,member_id);
+            },
+            AdminAction::SetQuorum(quorum) => {
+                if quorum > 100 {
+                    return Err(DaoError::InvalidQuorum)
+                }
+                self.data::<Data>().quorum = quorum;
+            },
+            AdminAction::VetoProposal(proposal_id) => {
+                if self.data::<Data>().vote.get(&proposal_id).is_none() {
+                    return Err(DaoError::ProposalDoesNotExist)
+                }
+                let mut vote = self.data::<Data>().vote.get(&proposal_id).unwrap();
+                if vote.vote_status != VoteStatus::InProgress {
+                    return Err(DaoError::VoteNotAvailable)
+                }
+                vote.vote_status = VoteStatus::Vetoed;
+                self.data::<Data>().vote.insert(&proposal_id,&vote);
+            },
+            AdminAction::AddSigner(signer) => {
+                if self.is_signer(signer.clone()) {
+                    return Err(DaoError::SignerAlreadyExists)
+                }
+                self.data::<Data>().signers.push(signer);
+                if self.data::<Data>().threshold < 2 {
+                    self.data::<Data>().threshold = 2;
+                }
+            },
+            AdminAction::RemoveSigner(signer) => {
+                if !self.is_signer(signer.clone()) {
+                    return Err(DaoError::SignerDoesNotExist)
+                }
+                let signers = self.data::<Data>().signers.len() as u32;
+                if !valid_threshold(self.data::<Data>().threshold,signers - 1) {
+                    return Err(DaoError::InvalidThreshold)
+                }
+                self.data::<Data>().signers.retain(|account| *account != signer);
+            },
+            AdminAction::ChangeThreshold(threshold) => {
+                let signers = self.data::<Data>().signers.len() as u32;
+                if !valid_threshold(threshold,signers) {
+                    return Err(DaoError::InvalidThreshold)
+                }
+                self.data::<Data>().threshold = threshold;
+            },
+            AdminAction::SetMinTurnout(min_turnout) => {
+                self.data::<Data>().min_turnout = min_turnout;
+            },
+            AdminAction::SetVotingPeriod(voting_period) => {
+                self.data::<Data>().voting_period = voting_period;
+            },
+            AdminAction::SetEligibilityPolicy(policy) => {
+                self.data::<Data>().eligibility = policy;
+            },
+            AdminAction::GrantRole(role,account) => {
+                if !self.data::<Data>().members.contains(&account) {
+                    return Err(DaoError::MemberDoesNotExist)
+                }
+                self.grant_role_internal(role,account)?;
+            },
+            AdminAction::RevokeRole(role,account) => {
+                self.revoke_role_internal(role,account)?;
+            },
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    impls::dao::types::{
+        Data,
+        DaoError,
+    },
+    impls::dao::dao::DaoEvents,
+    impls::dao::decay::DecayInternal,
+    traits::kudos::DaoKudos,
+    traits::points_token::PSP22,
+};
+use ink::prelude::vec::Vec;
+
+use openbrush::{
+    contracts::psp22::PSP22Error,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        String,
+    },
+};
+
+pub trait PointsInternal {
+    /// Credits newly awarded points to `account`.
+    fn mint_points(&mut self,account: AccountId,points: u32);
+
+    /// Takes points out of circulation, fails when `account` holds less.
+    fn burn_points(&mut self,account: AccountId,points: u32) -> Result<(),DaoError>;
+
+    /// Moves points between two balances without changing the supply.
+    fn move_points(&mut self,from: AccountId,to: AccountId,points: u32) -> Result<(),DaoError>;
+}
+
+impl<T> PSP22 for T
+where
+    T: Storage<Data>,
+{
+    /// Sum of the balances as of their last change. Pending decay is only
+    /// removed once a balance is touched, so this is an upper bound of the
+    /// decayed points.
+    default fn total_supply(&self) -> Balance {
+        self.data::<Data>().points_supply
+    }
+
+    /// Balance as of its last change, without pending decay, so that the
+    /// balances always add up to `total_supply`. `get_member_points` shows
+    /// the decayed points.
+    default fn balance_of(&self, owner: AccountId) -> Balance {
+        self.data::<Data>().member_points.get(&owner).unwrap_or(0) as Balance
+    }
+
+    default fn allowance(&self, _owner: AccountId, _spender: AccountId) -> Balance {
+        0
+    }
+
+    default fn transfer(&mut self, to: AccountId, value: Balance, _data: Vec<u8>) -> Result<(), PSP22Error> {
+        if value > u32::MAX as Balance {
+            return Err(PSP22Error::InsufficientBalance)
+        }
+
+        // Same rules as `transfer_points`, governed by the kudos config
+
+        match self.transfer_points(to,value as u32) {
+            Ok(()) => Ok(()),
+            Err(DaoError::InsufficientPoints) => Err(PSP22Error::InsufficientBalance),
+            Err(DaoError::PointTransfersDisabled) => Err(PSP22Error::Custom(String::from("PointTransfersDisabled"))),
+            Err(_) => Err(PSP22Error::Custom(String::from("TransferNotAllowed"))),
+        }
+    }
+
+    default fn transfer_from(&mut self, _from: AccountId, _to: AccountId, _value: Balance, _data: Vec<u8>) -> Result<(), PSP22Error> {
+        Err(PSP22Error::Custom(String::from("ApprovalsDisabled")))
+    }
+
+    default fn approve(&mut self, _spender: AccountId, _value: Balance) -> Result<(), PSP22Error> {
+        Err(PSP22Error::Custom(String::from("ApprovalsDisabled")))
+    }
+
+    default fn increase_allowance(&mut self, _spender: AccountId, _delta_value: Balance) -> Result<(), PSP22Error> {
+        Err(PSP22Error::Custom(String::from("ApprovalsDisabled")))
+    }
+
+    default fn decrease_allowance(&mut self, _spender: AccountId, _delta_value: Balance) -> Result<(), PSP22Error> {
+        Err(PSP22Error::Custom(String::from("ApprovalsDisabled")))
+    }
+}
+
+impl<T> PointsInternal for T
+where
+    T: Storage<Data>,
+{
+    default fn mint_points(&mut self,account: AccountId,points: u32) {
+        self.apply_decay(account.clone());
+
+        let balance = self.data::<Data>().member_points.get(&account).unwrap_or(0);
+        let minted = balance.saturating_add(points) - balance;
+
+        self.data::<Data>().member_points.insert(&account,&(balance + minted));
+        self.data::<Data>().points_supply += minted as Balance;
+
+        self.emit_points_transfer_event(None,Some(account),minted as Balance);
+    }
+
+    default fn burn_points(&mut self,account: AccountId,points: u32) -> Result<(),DaoError> {
+        self.apply_decay(account.clone());
+
+        let balance = self.data::<Data>().member_points.get(&account).unwrap_or(0);
+
+        if balance < points {
+            return Err(DaoError::InsufficientPoints)
+        }
+
+        self.data::<Data>().member_points.insert(&account,&(balance - points));
+        let supply = self.data::<Data>().points_supply;
+        self.data::<Data>().points_supply = supply.saturating_sub(points as Balance);
+
+        self.emit_points_transfer_event(Some(account),None,points as Balance);
+
+        Ok(())
+    }
+
+    default fn move_points(&mut self,from: AccountId,to: AccountId,points: u32) -> Result<(),DaoError> {
+        self.apply_decay(from.clone());
+        self.apply_decay(to.clone());
+
+        let balance = self.data::<Data>().member_points.get(&from).unwrap_or(0);
+
+        if balance < points {
+            return Err(DaoError::InsufficientPoints)
+        }
+
+        self.data::<Data>().member_points.insert(&from,&(balance - points));
+
+        let received = self.data::<Data>().member_points.get(&to).unwrap_or(0);
+        self.data::<Data>().member_points.insert(&to,&received.saturating_add(points));
+
+        self.emit_points_transfer_event(Some(from),Some(to),points as Balance);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    impls::dao::types::{
+        Data,
+        DaoError,
+        MemberProfile,
+        MAX_PROFILE_FIELD_LENGTH,
+        MAX_PROFILE_SKILLS,
+    },
+    impls::dao::dao::{
+        DaoEvents,
+        Internal,
+    },
+    traits::profile::DaoProfile,
+};
+
+use openbrush::{
+    traits::{
+        AccountId,
+        DefaultEnv,
+        Storage,
+    },
+};
+
+impl<T> DaoProfile for T
+where
+    T: Storage<Data>,
+{
+    default fn set_member_profile(&mut self, profile: MemberProfile) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        if !self.data::<Data>().members.contains(&caller) {
+            return Err(DaoError::MemberDoesNotExist)
+        }
+
+        if profile.display_name.len() > MAX_PROFILE_FIELD_LENGTH
+            || profile.avatar_uri.len() > MAX_PROFILE_FIELD_LENGTH
+            || profile.skills.len() > MAX_PROFILE_SKILLS
+            || profile.skills.iter().any(|skill| skill.len() > MAX_PROFILE_FIELD_LENGTH) {
+            return Err(DaoError::InvalidProfile)
+        }
+
+        self.data::<Data>().member_profile.insert(&caller,&profile);
+
+        self.emit_profile_updated_event(caller.clone());
+
+        self.record_activity(caller.clone());
+
+        Ok(())
+    }
+
+    default fn remove_member_profile(&mut self) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        if self.data::<Data>().member_profile.get(&caller).is_none() {
+            return Err(DaoError::ProfileDoesNotExist)
+        }
+
+        self.data::<Data>().member_profile.remove(&caller);
+
+        self.emit_profile_updated_event(caller);
+
+        Ok(())
+    }
+
+    default fn get_member_profile(&self, member: AccountId) -> Option<MemberProfile> {
+        self.data::<Data>().member_profile.get(&member)
+    }
+}
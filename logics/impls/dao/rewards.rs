@@ -0,0 +1,237 @@
+use crate::{
+    impls::dao::types::{
+        Data,
+        DaoError,
+        Redemption,
+        RedemptionId,
+        RedemptionTerms,
+        Asset,
+    },
+    impls::dao::dao::{
+        DaoEvents,
+        Internal,
+    },
+    impls::dao::points_token::PointsInternal,
+    impls::dao::tiers::TiersInternal,
+    impls::dao::treasury::TreasuryInternal,
+    traits::rewards::DaoRewards,
+};
+
+use openbrush::{
+    traits::{
+        AccountId,
+        Balance,
+        DefaultEnv,
+        Storage,
+    },
+};
+
+pub trait RewardsInternal {
+    /// Redemption window accepting points right now.
+    fn open_redemption(&self) -> Option<RedemptionId>;
+
+    fn validate_redemption_terms(&self,terms: &RedemptionTerms) -> Result<(),DaoError>;
+
+    /// Opens a window and reserves its whole pool in the treasury.
+    fn start_redemption(&mut self,terms: RedemptionTerms) -> RedemptionId;
+
+    /// Releases the part of the pool of an ended window that nobody redeemed.
+    fn close_redemption_internal(&mut self,redemption_id: RedemptionId) -> Result<(),DaoError>;
+}
+
+impl<T> DaoRewards for T
+where
+    T: Storage<Data>,
+{
+    default fn redeem_points(&mut self, points: u32) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        if !self.data::<Data>().members.contains(&caller) {
+            return Err(DaoError::MemberDoesNotExist)
+        }
+
+        if self.is_expired(caller.clone()) {
+            return Err(DaoError::MembershipExpired)
+        }
+
+        let redemption_id = match self.open_redemption() {
+            Some(redemption_id) => redemption_id,
+            None => return Err(DaoError::NoOpenRedemption),
+        };
+
+        let mut redemption = self.data::<Data>().redemptions.get(&redemption_id).unwrap();
+
+        let reward = match (points as Balance).checked_mul(redemption.rate) {
+            Some(reward) => reward,
+            None => return Err(DaoError::RewardPoolExceeded),
+        };
+
+        if redemption.pool - redemption.committed < reward {
+            return Err(DaoError::RewardPoolExceeded)
+        }
+
+        self.burn_points(caller.clone(),points)?;
+        self.refresh_tier(caller.clone());
+
+        redemption.points_redeemed += points;
+        redemption.committed += reward;
+        self.data::<Data>().redemptions.insert(&redemption_id,&redemption);
+
+        let owed = self.data::<Data>().redemption_rewards.get(&(redemption_id,caller.clone())).unwrap_or(0);
+        self.data::<Data>().redemption_rewards.insert(&(redemption_id,caller.clone()),&(owed + reward));
+
+        self.emit_points_redeemed_event(caller.clone(),redemption_id,points,reward);
+
+        self.record_activity(caller.clone());
+
+        Ok(())
+    }
+
+    default fn claim_rewards(&mut self, redemption_id: RedemptionId) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        let redemption = match self.data::<Data>().redemptions.get(&redemption_id) {
+            Some(redemption) => redemption,
+            None => return Err(DaoError::NothingToClaim),
+        };
+
+        if Self::env().block_timestamp() < redemption.end {
+            return Err(DaoError::RedemptionNotFinished)
+        }
+
+        let amount = self.data::<Data>().redemption_rewards.get(&(redemption_id,caller.clone())).unwrap_or(0);
+
+        if amount == 0 {
+            return Err(DaoError::NothingToClaim)
+        }
+
+        let claim = (redemption_id,caller.clone());
+
+        self.pay_reserved(&redemption.asset,caller.clone(),amount,|dao: &mut Self,paid| {
+            if paid {
+                dao.data::<Data>().redemption_rewards.remove(&claim);
+            } else {
+                dao.data::<Data>().redemption_rewards.insert(&claim,&amount);
+            }
+        }).map_err(|_| DaoError::RewardTransferFailed)?;
+
+        self.emit_rewards_claimed_event(caller,redemption_id,amount);
+
+        Ok(())
+    }
+
+    default fn close_redemption(&mut self, redemption_id: RedemptionId) -> Result<(),DaoError> {
+        self.close_redemption_internal(redemption_id)
+    }
+
+    default fn get_current_redemption(&self) -> Option<RedemptionId> {
+        self.open_redemption()
+    }
+
+    default fn get_redemption(&self, redemption_id: RedemptionId) -> Option<Redemption> {
+        self.data::<Data>().redemptions.get(&redemption_id)
+    }
+
+    default fn get_number_of_redemptions(&self) -> u32 {
+        self.data::<Data>().redemption_id
+    }
+
+    default fn get_pending_reward(&self, redemption_id: RedemptionId, member: AccountId) -> Balance {
+        self.data::<Data>().redemption_rewards.get(&(redemption_id,member)).unwrap_or(0)
+    }
+}
+
+impl<T> RewardsInternal for T
+where
+    T: Storage<Data>,
+{
+    default fn open_redemption(&self) -> Option<RedemptionId> {
+        let redemption_id = self.data::<Data>().current_redemption?;
+        let redemption = self.data::<Data>().redemptions.get(&redemption_id)?;
+
+        if Self::env().block_timestamp() < redemption.end {
+            Some(redemption_id)
+        } else {
+            None
+        }
+    }
+
+    default fn validate_redemption_terms(&self,terms: &RedemptionTerms) -> Result<(),DaoError> {
+        if terms.rate == 0 || terms.pool == 0 || terms.length == 0 {
+            return Err(DaoError::InvalidRedemption)
+        }
+
+        if let Asset::PSP22(token) = &terms.asset {
+            if !self.data::<Data>().treasury_tokens.contains(token) {
+                return Err(DaoError::TokenNotAllowed)
+            }
+        }
+
+        if self.open_redemption().is_some() {
+            return Err(DaoError::RedemptionAlreadyOpen)
+        }
+
+        // The pool must be funded before members burn points for it
+
+        if terms.pool > self.available_balance(&terms.asset) {
+            return Err(DaoError::InsufficientTreasury)
+        }
+
+        Ok(())
+    }
+
+    default fn start_redemption(&mut self,terms: RedemptionTerms) -> RedemptionId {
+        let now = Self::env().block_timestamp();
+
+        if let Some(previous) = self.data::<Data>().current_redemption {
+            let _ = self.close_redemption_internal(previous);
+        }
+
+        let redemption_id = self.data::<Data>().redemption_id + 1;
+        self.data::<Data>().redemption_id = redemption_id;
+
+        let redemption = Redemption {
+            start: now,
+            end: now + terms.length,
+            asset: terms.asset,
+            rate: terms.rate,
+            pool: terms.pool,
+            points_redeemed: 0,
+            committed: 0,
+            closed: false,
+        };
+
+        // Rewards owed to members cannot be spent from the treasury
+
+        self.reserve_funds(&redemption.asset,redemption.pool);
+
+        self.data::<Data>().redemptions.insert(&redemption_id,&redemption);
+        self.data::<Data>().current_redemption = Some(redemption_id);
+
+        redemption_id
+    }
+
+    default fn close_redemption_internal(&mut self,redemption_id: RedemptionId) -> Result<(),DaoError> {
+        let mut redemption = match self.data::<Data>().redemptions.get(&redemption_id) {
+            Some(redemption) => redemption,
+            None => return Err(DaoError::RedemptionDoesNotExist),
+        };
+
+        if Self::env().block_timestamp() < redemption.end {
+            return Err(DaoError::RedemptionNotFinished)
+        }
+
+        if redemption.closed {
+            return Err(DaoError::RedemptionAlreadyClosed)
+        }
+
+        // What was committed stays reserved until it is claimed
+
+        self.release_funds(&redemption.asset,redemption.pool - redemption.committed);
+
+        redemption.closed = true;
+        self.data::<Data>().redemptions.insert(&redemption_id,&redemption);
+
+        Ok(())
+    }
+}
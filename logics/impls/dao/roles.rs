@@ -0,0 +1,112 @@
+use crate::{
+    impls::dao::types::{
+        Data,
+        DaoError,
+        RoleType,
+        ROLES,
+    },
+    impls::dao::dao::DaoEvents,
+    traits::roles::DaoRoles,
+};
+use ink::prelude::vec::Vec;
+
+use openbrush::{
+    modifier_definition,
+    traits::{
+        AccountId,
+        DefaultEnv,
+        Storage,
+    },
+};
+
+/// Throws if the caller does not have the `role`.
+#[modifier_definition]
+pub fn only_role<T, F, R, E>(instance: &mut T, body: F, role: RoleType) -> Result<R, E>
+where
+    T: Storage<Data>,
+    F: FnOnce(&mut T) -> Result<R, E>,
+    E: From<DaoError>,
+{
+    if !instance.data::<Data>().has_role(role,T::env().caller()) {
+        return Err(From::from(DaoError::MissingRole))
+    }
+    body(instance)
+}
+
+pub trait RolesInternal {
+    fn grant_role_internal(&mut self,role: RoleType,account: AccountId) -> Result<(),DaoError>;
+
+    fn revoke_role_internal(&mut self,role: RoleType,account: AccountId) -> Result<(),DaoError>;
+
+    /// Drops every role of `account`, used when it stops being a member.
+    fn clear_roles(&mut self,account: AccountId);
+}
+
+impl<T> DaoRoles for T
+where
+    T: Storage<Data>,
+{
+    default fn renounce_role(&mut self, role: RoleType) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+        self.revoke_role_internal(role,caller)
+    }
+
+    default fn has_role(&self, role: RoleType, account: AccountId) -> bool {
+        self.data::<Data>().has_role(role,account)
+    }
+
+    default fn get_role_members(&self, role: RoleType) -> Vec<AccountId> {
+        self.data::<Data>().role_members.get(&role).unwrap_or_default()
+    }
+
+    default fn get_roles(&self, account: AccountId) -> Vec<RoleType> {
+        ROLES.iter().filter(|role| self.data::<Data>().has_role(**role,account.clone())).cloned().collect()
+    }
+}
+
+impl<T> RolesInternal for T
+where
+    T: Storage<Data>,
+{
+    default fn grant_role_internal(&mut self,role: RoleType,account: AccountId) -> Result<(),DaoError> {
+        if !ROLES.contains(&role) {
+            return Err(DaoError::InvalidRole)
+        }
+
+        let mut holders = self.data::<Data>().role_members.get(&role).unwrap_or_default();
+
+        if holders.contains(&account) {
+            return Err(DaoError::RoleAlreadyGranted)
+        }
+
+        holders.push(account.clone());
+        self.data::<Data>().role_members.insert(&role,&holders);
+
+        self.emit_role_granted_event(role,account);
+
+        Ok(())
+    }
+
+    default fn revoke_role_internal(&mut self,role: RoleType,account: AccountId) -> Result<(),DaoError> {
+        let mut holders = self.data::<Data>().role_members.get(&role).unwrap_or_default();
+
+        if !holders.contains(&account) {
+            return Err(DaoError::RoleNotGranted)
+        }
+
+        holders.retain(|holder| *holder != account);
+        self.data::<Data>().role_members.insert(&role,&holders);
+
+        self.emit_role_revoked_event(role,account);
+
+        Ok(())
+    }
+
+    default fn clear_roles(&mut self,account: AccountId) {
+        for role in ROLES.iter() {
+            if self.data::<Data>().has_role(*role,account.clone()) {
+                let _ = self.revoke_role_internal(*role,account.clone());
+            }
+        }
+    }
+}
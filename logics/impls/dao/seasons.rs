@@ -0,0 +1,102 @@
+use crate::{
+    impls::dao::types::{
+        Data,
+        DaoError,
+        Season,
+        SeasonId,
+        ADMIN,
+    },
+    impls::dao::roles::only_role,
+    impls::dao::dao::DaoEvents,
+    traits::seasons::DaoSeasons,
+};
+use ink::prelude::vec::Vec;
+
+use openbrush::{
+    modifiers,
+    traits::{
+        AccountId,
+        DefaultEnv,
+        Storage,
+        Timestamp,
+    },
+};
+
+pub trait SeasonsInternal {
+    /// Credits points earned by `account` to the current season.
+    fn add_season_points(&mut self,account: AccountId,points: u32);
+}
+
+impl<T> DaoSeasons for T
+where
+    T: Storage<Data>,
+{
+    default fn close_season(&mut self) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+        let now = Self::env().block_timestamp();
+
+        // Admins close seasons at will, anyone can once the season length has passed
+
+        let length = self.data::<Data>().season_length;
+        let start = self.data::<Data>().season_start;
+
+        if !self.data::<Data>().has_role(ADMIN,caller) && (length == 0 || now < start + length) {
+            return Err(DaoError::SeasonNotFinished)
+        }
+
+        let season_id = self.data::<Data>().season_id;
+        let members = self.data::<Data>().members.clone();
+
+        let snapshot: Vec<(AccountId,u32)> = members.into_iter()
+            .map(|member| (member.clone(),self.data::<Data>().season_points.get(&(season_id,member)).unwrap_or(0)))
+            .collect();
+
+        self.data::<Data>().season_snapshot.insert(&season_id,&snapshot);
+        self.data::<Data>().seasons.insert(&season_id,&Season { start: start, end: now });
+
+        self.data::<Data>().season_id = season_id + 1;
+        self.data::<Data>().season_start = now;
+
+        self.emit_season_closed_event(season_id);
+
+        Ok(())
+    }
+
+    #[modifiers(only_role(ADMIN))]
+    default fn set_season_length(&mut self, length: Timestamp) -> Result<(),DaoError> {
+        self.data::<Data>().season_length = length;
+        Ok(())
+    }
+
+    default fn get_season_length(&self) -> Timestamp {
+        self.data::<Data>().season_length
+    }
+
+    default fn get_current_season(&self) -> (SeasonId,Timestamp) {
+        (self.data::<Data>().season_id,self.data::<Data>().season_start)
+    }
+
+    default fn get_season(&self, season_id: SeasonId) -> Option<Season> {
+        self.data::<Data>().seasons.get(&season_id)
+    }
+
+    default fn get_season_points(&self, season_id: SeasonId, member: AccountId) -> u32 {
+        self.data::<Data>().season_points.get(&(season_id,member)).unwrap_or(0)
+    }
+
+    default fn get_season_snapshot(&self, season_id: SeasonId) -> Vec<(AccountId,u32)> {
+        self.data::<Data>().season_snapshot.get(&season_id).unwrap_or_default()
+    }
+}
+
+impl<T> SeasonsInternal for T
+where
+    T: Storage<Data>,
+{
+    default fn add_season_points(&mut self,account: AccountId,points: u32) {
+        let season_id = self.data::<Data>().season_id;
+        let earned = self.data::<Data>().season_points.get(&(season_id,account.clone())).unwrap_or(0);
+
+        self.data::<Data>().season_points.insert(&(season_id,account),&earned.saturating_add(points));
+    }
+}
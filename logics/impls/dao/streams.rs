@@ -0,0 +1,199 @@
+use crate::{
+    impls::dao::types::{
+        Data,
+        DaoError,
+        Stream,
+        StreamId,
+        StreamStatus,
+        StreamTerms,
+    },
+    impls::dao::dao::DaoEvents,
+    impls::dao::treasury::TreasuryInternal,
+    traits::streams::DaoStreams,
+};
+use ink::prelude::vec::Vec;
+
+use openbrush::{
+    traits::{
+        AccountId,
+        Balance,
+        DefaultEnv,
+        Storage,
+        Timestamp,
+    },
+};
+
+pub trait StreamsInternal {
+    fn validate_stream_terms(&self,terms: &StreamTerms) -> Result<(),DaoError>;
+
+    /// Creates the stream and reserves its whole amount in the treasury.
+    fn create_stream(&mut self,terms: StreamTerms) -> StreamId;
+
+    /// Stops the stream and pays the beneficiary what it accrued.
+    fn cancel_stream(&mut self,stream_id: StreamId);
+
+    /// Pays what the stream accrued since the last withdrawal.
+    fn settle_stream(&mut self,stream_id: StreamId) -> Result<Balance,DaoError>;
+}
+
+/// Amount accrued by `stream` at `now` and not withdrawn yet.
+pub fn withdrawable(stream: &Stream,now: Timestamp) -> Balance {
+    if now < stream.cliff {
+        return 0
+    }
+
+    let elapsed = now.min(stream.end).saturating_sub(stream.start);
+
+    (elapsed as Balance * stream.rate).saturating_sub(stream.withdrawn)
+}
+
+impl<T> DaoStreams for T
+where
+    T: Storage<Data>,
+{
+    default fn withdraw_stream(&mut self, stream_id: StreamId) -> Result<(),DaoError> {
+        let stream = match self.data::<Data>().streams.get(&stream_id) {
+            Some(stream) => stream,
+            None => return Err(DaoError::StreamDoesNotExist),
+        };
+
+        if stream.beneficiary != Self::env().caller() {
+            return Err(DaoError::IneligibleCaller)
+        }
+
+        if self.settle_stream(stream_id)? == 0 {
+            return Err(DaoError::NothingToWithdraw)
+        }
+
+        Ok(())
+    }
+
+    default fn get_stream(&self, stream_id: StreamId) -> Option<Stream> {
+        self.data::<Data>().streams.get(&stream_id)
+    }
+
+    default fn get_withdrawable(&self, stream_id: StreamId) -> Balance {
+        match self.data::<Data>().streams.get(&stream_id) {
+            Some(stream) => withdrawable(&stream,Self::env().block_timestamp()),
+            None => 0,
+        }
+    }
+
+    default fn get_member_streams(&self, member: AccountId) -> Vec<StreamId> {
+        self.data::<Data>().member_streams.get(&member).unwrap_or_default()
+    }
+
+    default fn get_number_of_streams(&self) -> u32 {
+        self.data::<Data>().stream_id
+    }
+}
+
+impl<T> StreamsInternal for T
+where
+    T: Storage<Data>,
+{
+    default fn validate_stream_terms(&self,terms: &StreamTerms) -> Result<(),DaoError> {
+        if !self.data::<Data>().members.contains(&terms.beneficiary) {
+            return Err(DaoError::MemberDoesNotExist)
+        }
+
+        if terms.rate == 0
+            || terms.end <= terms.start
+            || terms.cliff < terms.start
+            || terms.cliff > terms.end
+            || terms.start < Self::env().block_timestamp() {
+            return Err(DaoError::InvalidStream)
+        }
+
+        let total = match ((terms.end - terms.start) as Balance).checked_mul(terms.rate) {
+            Some(total) => total,
+            None => return Err(DaoError::InvalidStream),
+        };
+
+        self.validate_spend(&terms.asset,total)
+    }
+
+    default fn create_stream(&mut self,terms: StreamTerms) -> StreamId {
+        let stream_id = self.data::<Data>().stream_id + 1;
+        self.data::<Data>().stream_id = stream_id;
+
+        // The whole stream is set aside so that spends cannot starve it
+
+        let total = (terms.end - terms.start) as Balance * terms.rate;
+        self.reserve_funds(&terms.asset,total);
+
+        let stream = Stream {
+            beneficiary: terms.beneficiary.clone(),
+            asset: terms.asset,
+            rate: terms.rate,
+            start: terms.start,
+            end: terms.end,
+            cliff: terms.cliff,
+            withdrawn: 0,
+            status: StreamStatus::Active,
+        };
+
+        self.data::<Data>().streams.insert(&stream_id,&stream);
+
+        let mut streams = self.data::<Data>().member_streams.get(&terms.beneficiary).unwrap_or_default();
+        streams.push(stream_id);
+        self.data::<Data>().member_streams.insert(&terms.beneficiary,&streams);
+
+        self.emit_stream_created_event(stream_id,terms.beneficiary);
+
+        stream_id
+    }
+
+    default fn cancel_stream(&mut self,stream_id: StreamId) {
+        let mut stream = match self.data::<Data>().streams.get(&stream_id) {
+            Some(stream) => stream,
+            None => return,
+        };
+
+        if stream.status != StreamStatus::Active {
+            return
+        }
+
+        // Before the cliff nothing has been earned yet
+
+        let now = Self::env().block_timestamp();
+        let end = if now < stream.cliff { stream.start } else { now.min(stream.end) };
+
+        let unearned = (stream.end - end) as Balance * stream.rate;
+        self.release_funds(&stream.asset,unearned);
+
+        stream.end = end;
+        stream.status = StreamStatus::Cancelled;
+        self.data::<Data>().streams.insert(&stream_id,&stream);
+
+        // A failed payment stays withdrawable by the beneficiary
+
+        let settled = self.settle_stream(stream_id).unwrap_or(0);
+
+        self.emit_stream_cancelled_event(stream_id,settled);
+    }
+
+    default fn settle_stream(&mut self,stream_id: StreamId) -> Result<Balance,DaoError> {
+        let stream = self.data::<Data>().streams.get(&stream_id).unwrap();
+
+        let amount = withdrawable(&stream,Self::env().block_timestamp());
+
+        if amount == 0 {
+            return Ok(0)
+        }
+
+        self.pay_reserved(&stream.asset,stream.beneficiary.clone(),amount,|dao: &mut Self,paid| {
+            let mut stream = dao.data::<Data>().streams.get(&stream_id).unwrap();
+            if paid {
+                stream.withdrawn += amount;
+            } else {
+                stream.withdrawn -= amount;
+            }
+            dao.data::<Data>().streams.insert(&stream_id,&stream);
+        })?;
+
+        self.emit_stream_withdrawn_event(stream_id,stream.beneficiary,amount);
+
+        Ok(amount)
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{
+    impls::dao::types::{
+        Data,
+        DaoError,
+        Tier,
+        TierRequirements,
+        ADMIN,
+    },
+    impls::dao::roles::only_role,
+    impls::dao::dao::DaoEvents,
+    impls::dao::decay::DecayInternal,
+    traits::tiers::DaoTiers,
+};
+use ink::prelude::vec::Vec;
+
+use openbrush::{
+    modifiers,
+    traits::{
+        AccountId,
+        Storage,
+    },
+};
+
+pub trait TiersInternal {
+    /// Number of tiers whose threshold is covered by `points`.
+    fn tier_level(&self,points: u32) -> u32;
+
+    fn member_tier_level(&self,account: AccountId) -> u32;
+
+    /// Stores the tier of `account` after its points changed, emitting an event if it moved.
+    fn refresh_tier(&mut self,account: AccountId);
+}
+
+impl<T> DaoTiers for T
+where
+    T: Storage<Data>,
+{
+    #[modifiers(only_role(ADMIN))]
+    default fn set_tiers(&mut self, tiers: Vec<Tier>) -> Result<(),DaoError> {
+        for pair in tiers.windows(2) {
+            if pair[0].min_points >= pair[1].min_points {
+                return Err(DaoError::InvalidTiers)
+            }
+        }
+
+        self.data::<Data>().tiers = tiers;
+
+        let members = self.data::<Data>().members.clone();
+
+        for member in members.into_iter() {
+            self.refresh_tier(member);
+        }
+
+        Ok(())
+    }
+
+    #[modifiers(only_role(ADMIN))]
+    default fn set_tier_requirements(&mut self, requirements: TierRequirements) -> Result<(),DaoError> {
+        self.data::<Data>().tier_requirements = requirements;
+        Ok(())
+    }
+
+    default fn get_tiers(&self) -> Vec<Tier> {
+        self.data::<Data>().tiers.clone()
+    }
+
+    default fn get_tier_requirements(&self) -> TierRequirements {
+        self.data::<Data>().tier_requirements.clone()
+    }
+
+    default fn get_member_tier_level(&self, member: AccountId) -> u32 {
+        self.member_tier_level(member)
+    }
+
+    default fn get_member_tier(&self, member: AccountId) -> Option<Tier> {
+        let level = self.member_tier_level(member);
+
+        if level == 0 {
+            return None
+        }
+
+        self.data::<Data>().tiers.get(level as usize - 1).cloned()
+    }
+}
+
+impl<T> TiersInternal for T
+where
+    T: Storage<Data>,
+{
+    default fn tier_level(&self,points: u32) -> u32 {
+        self.data::<Data>().tiers.iter().filter(|tier| points >= tier.min_points).count() as u32
+    }
+
+    default fn member_tier_level(&self,account: AccountId) -> u32 {
+        self.tier_level(self.points_of(account))
+    }
+
+    default fn refresh_tier(&mut self,account: AccountId) {
+        let level = self.member_tier_level(account.clone());
+        let previous = self.data::<Data>().member_tier.get(&account).unwrap_or(0);
+
+        if level != previous {
+            self.data::<Data>().member_tier.insert(&account,&level);
+            self.emit_tier_changed_event(account,level);
+        }
+    }
+}
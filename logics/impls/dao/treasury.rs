@@ -0,0 +1,246 @@
+use crate::{
+    impls::dao::types::{
+        Asset,
+        Data,
+        DaoError,
+        ProposalId,
+        SpendId,
+        SpendStatus,
+        TreasurySpend,
+        TREASURER,
+    },
+    impls::dao::roles::only_role,
+    impls::dao::dao::DaoEvents,
+    traits::treasury::DaoTreasury,
+};
+use ink::prelude::vec::Vec;
+
+use openbrush::{
+    contracts::psp22::PSP22Ref,
+    modifiers,
+    traits::{
+        AccountId,
+        Balance,
+        DefaultEnv,
+        Storage,
+    },
+};
+
+pub trait TreasuryInternal {
+    /// Amount of `asset` held by the DAO.
+    fn asset_balance(&self,asset: &Asset) -> Balance;
+
+    /// Amount of `asset` not owed to members as rewards.
+    fn available_balance(&self,asset: &Asset) -> Balance;
+
+    fn validate_spend(&self,asset: &Asset,amount: Balance) -> Result<(),DaoError>;
+
+    /// Pays a passed spend proposal and records it in the spend history.
+    fn spend(&mut self,proposal_id: ProposalId,asset: Asset,beneficiary: AccountId,amount: Balance);
+
+    fn transfer_asset(&self,asset: &Asset,to: AccountId,amount: Balance) -> Result<(),DaoError>;
+
+    /// Sets funds aside so that they cannot be spent.
+    fn reserve_funds(&mut self,asset: &Asset,amount: Balance);
+
+    fn release_funds(&mut self,asset: &Asset,amount: Balance);
+
+    /// Pays `amount` of reserved `asset` to `to` and releases it from the reserve.
+    /// `record(self, true)` marks the amount as paid before the transfer, so that it
+    /// cannot be paid twice, and `record(self, false)` undoes it if the transfer fails.
+    fn pay_reserved<F>(&mut self,asset: &Asset,to: AccountId,amount: Balance,record: F) -> Result<(),DaoError>
+    where
+        F: Fn(&mut Self,bool),
+        Self: Sized;
+}
+
+impl<T> DaoTreasury for T
+where
+    T: Storage<Data>,
+{
+    default fn deposit(&mut self) -> Result<(),DaoError> {
+        let amount = Self::env().transferred_value();
+
+        if amount == 0 {
+            return Err(DaoError::ZeroAmount)
+        }
+
+        self.emit_deposit_event(Self::env().caller(),amount);
+
+        Ok(())
+    }
+
+    #[modifiers(only_role(TREASURER))]
+    default fn add_treasury_token(&mut self, token: AccountId) -> Result<(),DaoError> {
+        if self.data::<Data>().treasury_tokens.contains(&token) {
+            return Err(DaoError::TokenAlreadyAllowed)
+        }
+
+        self.data::<Data>().treasury_tokens.push(token);
+
+        Ok(())
+    }
+
+    #[modifiers(only_role(TREASURER))]
+    default fn remove_treasury_token(&mut self, token: AccountId) -> Result<(),DaoError> {
+        if !self.data::<Data>().treasury_tokens.contains(&token) {
+            return Err(DaoError::TokenNotAllowed)
+        }
+
+        self.data::<Data>().treasury_tokens.retain(|allowed| *allowed != token);
+
+        Ok(())
+    }
+
+    /// Only tokens off the allowlist can be recovered, the others are spent through governance.
+    #[modifiers(only_role(TREASURER))]
+    default fn recover_tokens(&mut self, token: AccountId, to: AccountId, amount: Balance) -> Result<(),DaoError> {
+        if self.data::<Data>().treasury_tokens.contains(&token) {
+            return Err(DaoError::TokenNotRecoverable)
+        }
+
+        if amount == 0 {
+            return Err(DaoError::ZeroAmount)
+        }
+
+        let asset = Asset::PSP22(token.clone());
+
+        if amount > self.available_balance(&asset) {
+            return Err(DaoError::InsufficientTreasury)
+        }
+
+        self.transfer_asset(&asset,to.clone(),amount)?;
+
+        self.emit_tokens_recovered_event(token,to,amount);
+
+        Ok(())
+    }
+
+    default fn get_treasury_tokens(&self) -> Vec<AccountId> {
+        self.data::<Data>().treasury_tokens.clone()
+    }
+
+    default fn get_treasury_balance(&self) -> Balance {
+        self.asset_balance(&Asset::Native)
+    }
+
+    default fn get_available_balance(&self) -> Balance {
+        self.available_balance(&Asset::Native)
+    }
+
+    default fn get_token_balance(&self, token: AccountId) -> Balance {
+        self.asset_balance(&Asset::PSP22(token))
+    }
+
+    default fn get_available_token_balance(&self, token: AccountId) -> Balance {
+        self.available_balance(&Asset::PSP22(token))
+    }
+
+    default fn get_spend(&self, spend_id: SpendId) -> Option<TreasurySpend> {
+        self.data::<Data>().treasury_spends.get(&spend_id)
+    }
+
+    default fn get_number_of_spends(&self) -> u32 {
+        self.data::<Data>().spend_id
+    }
+}
+
+impl<T> TreasuryInternal for T
+where
+    T: Storage<Data>,
+{
+    default fn asset_balance(&self,asset: &Asset) -> Balance {
+        match asset {
+            Asset::Native => Self::env().balance(),
+            Asset::PSP22(token) => PSP22Ref::balance_of(token,Self::env().account_id()),
+        }
+    }
+
+    default fn available_balance(&self,asset: &Asset) -> Balance {
+        let reserved = self.data::<Data>().reserved_funds.get(asset).unwrap_or(0);
+        self.asset_balance(asset).saturating_sub(reserved)
+    }
+
+    default fn validate_spend(&self,asset: &Asset,amount: Balance) -> Result<(),DaoError> {
+        if amount == 0 {
+            return Err(DaoError::ZeroAmount)
+        }
+
+        if let Asset::PSP22(token) = asset {
+            if !self.data::<Data>().treasury_tokens.contains(token) {
+                return Err(DaoError::TokenNotAllowed)
+            }
+        }
+
+        if amount > self.available_balance(asset) {
+            return Err(DaoError::InsufficientTreasury)
+        }
+
+        Ok(())
+    }
+
+    default fn spend(&mut self,proposal_id: ProposalId,asset: Asset,beneficiary: AccountId,amount: Balance) {
+        // The balance may have changed while the proposal was voted
+
+        let paid = self.validate_spend(&asset,amount).is_ok()
+            && self.transfer_asset(&asset,beneficiary.clone(),amount).is_ok();
+
+        let spend_id = self.data::<Data>().spend_id + 1;
+        self.data::<Data>().spend_id = spend_id;
+
+        let spend = TreasurySpend {
+            proposal_id,
+            asset: asset.clone(),
+            beneficiary: beneficiary.clone(),
+            amount,
+            timestamp: Self::env().block_timestamp(),
+            status: if paid { SpendStatus::Paid } else { SpendStatus::Failed },
+        };
+
+        self.data::<Data>().treasury_spends.insert(&spend_id,&spend);
+
+        if paid {
+            self.emit_treasury_spent_event(spend_id,asset,beneficiary,amount);
+        }
+    }
+
+    default fn transfer_asset(&self,asset: &Asset,to: AccountId,amount: Balance) -> Result<(),DaoError> {
+        let transferred = match asset {
+            Asset::Native => Self::env().transfer(to,amount).is_ok(),
+            Asset::PSP22(token) => PSP22Ref::transfer(token,to,amount,Vec::new()).is_ok(),
+        };
+
+        if !transferred {
+            return Err(DaoError::TokenTransferFailed)
+        }
+
+        Ok(())
+    }
+
+    default fn reserve_funds(&mut self,asset: &Asset,amount: Balance) {
+        let reserved = self.data::<Data>().reserved_funds.get(asset).unwrap_or(0);
+        self.data::<Data>().reserved_funds.insert(asset,&(reserved + amount));
+    }
+
+    default fn release_funds(&mut self,asset: &Asset,amount: Balance) {
+        let reserved = self.data::<Data>().reserved_funds.get(asset).unwrap_or(0);
+        self.data::<Data>().reserved_funds.insert(asset,&reserved.saturating_sub(amount));
+    }
+
+    default fn pay_reserved<F>(&mut self,asset: &Asset,to: AccountId,amount: Balance,record: F) -> Result<(),DaoError>
+    where
+        F: Fn(&mut Self,bool),
+        Self: Sized,
+    {
+        record(self,true);
+
+        if let Err(error) = self.transfer_asset(asset,to,amount) {
+            record(self,false);
+            return Err(error)
+        }
+
+        self.release_funds(asset,amount);
+
+        Ok(())
+    }
+}
@@ -1,12 +1,16 @@
+use crate::impls::eligibility::types::EligibilityPolicy;
 use ink::prelude::vec::Vec;
+use ink::primitives::Hash;
 use openbrush::{
     contracts::{
         ownable::OwnableError,
+        psp34::Id,
         reentrancy_guard::ReentrancyGuardError,
     },
     storage::Mapping,
     traits::{
         AccountId,
+        Balance,
         String,
         Timestamp,
         ZERO_ADDRESS,
@@ -18,11 +22,43 @@ use scale::{
 };
 
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+/// Voting period of proposals opened by the contract itself (7 days in milliseconds)
+pub const DEFAULT_VOTING_PERIOD: Timestamp = 604_800_000;
 pub type ProposalId = u32;
-pub type TokenId = u32;
+pub type TokenId = Id;
+/// Member counter that `member_token` recorded before `TokenId` became a PSP34 `Id`
+pub type LegacyTokenId = u32;
 pub type ProjectId = u32;
 pub type TaskId = u32;
+pub type ActionId = u32;
+pub type RoleType = u32;
+pub type AwardId = u32;
+pub type EpochId = u32;
+pub type SeasonId = u32;
+pub type BadgeId = u32;
+pub type RedemptionId = u32;
+pub type SpendId = u32;
+pub type StreamId = u32;
+/// Maximum length in bytes of the text fields of a member profile
+pub const MAX_PROFILE_FIELD_LENGTH: usize = 256;
+/// Maximum number of skills in a member profile
+pub const MAX_PROFILE_SKILLS: usize = 16;
+/// Maximum number of members a single invite can admit
+pub const MAX_INVITE_USES: u32 = 100;
 
+/// Can change the DAO configuration
+pub const ADMIN: RoleType = ink::selector_id!("ADMIN");
+/// Can add and remove members and manage the allowlist
+pub const MEMBERSHIP_MANAGER: RoleType = ink::selector_id!("MEMBERSHIP_MANAGER");
+/// Can create projects
+pub const PROJECT_LEAD: RoleType = ink::selector_id!("PROJECT_LEAD");
+/// Can be named reviewer of tasks
+pub const REVIEWER: RoleType = ink::selector_id!("REVIEWER");
+/// Can manage the DAO funds
+pub const TREASURER: RoleType = ink::selector_id!("TREASURER");
+pub const ROLES: [RoleType; 5] = [ADMIN, MEMBERSHIP_MANAGER, PROJECT_LEAD, REVIEWER, TREASURER];
+
+/// Fields are only ever appended, so that upgraded contracts keep reading the existing storage.
 #[derive(Debug)]
 #[openbrush::upgradeable_storage(STORAGE_KEY)]
 pub struct Data {
@@ -32,7 +68,8 @@ pub struct Data {
     pub task: Mapping<TaskId,Task>,
     pub project: Mapping<ProjectId,Project>,
     pub members: Vec<AccountId>,
-    pub member_token: Mapping<AccountId,TokenId>,
+    /// Id recorded at join before memberships were bound to an NFT, kept for the storage layout
+    pub member_token: Mapping<AccountId,LegacyTokenId>,
     pub member_points: Mapping<AccountId,u32>,
     pub member_votes: Mapping<(AccountId,ProposalId),bool>,
     pub member_tasks: Mapping<AccountId,Vec<TaskId>>,
@@ -45,6 +82,85 @@ pub struct Data {
     pub member_id: u32,
     pub project_id: u32,
     pub task_id: u32,
+    pub admin_action: Mapping<ActionId,AdminProposal>,
+    pub signers: Vec<AccountId>,
+    pub threshold: u32,
+    pub action_id: u32,
+    pub applications: Mapping<AccountId,ProposalId>,
+    pub voting_period: Timestamp,
+    pub reviewer_tasks: Mapping<AccountId,Vec<TaskId>>,
+    pub member_projects: Mapping<AccountId,Vec<ProjectId>>,
+    pub member_nft: Mapping<AccountId,(AccountId,TokenId)>,
+    pub token_member: Mapping<(AccountId,TokenId),AccountId>,
+    pub eligibility: EligibilityPolicy,
+    pub vouches: Mapping<AccountId,Vec<AccountId>>,
+    pub vouch_stakes: Mapping<(AccountId,AccountId),u32>,
+    pub vouch_admitted: Mapping<AccountId,Timestamp>,
+    pub vouch_config: VouchConfig,
+    pub member_expiry: Mapping<AccountId,Timestamp>,
+    pub membership_terms: MembershipTerms,
+    pub last_activity: Mapping<AccountId,Timestamp>,
+    pub inactivity_period: Timestamp,
+    pub invites: Mapping<Hash,Invite>,
+    pub invited_by: Mapping<AccountId,AccountId>,
+    pub member_invitees: Mapping<AccountId,Vec<AccountId>>,
+    pub role_members: Mapping<RoleType,Vec<AccountId>>,
+    pub member_tier: Mapping<AccountId,u32>,
+    pub tiers: Vec<Tier>,
+    pub tier_requirements: TierRequirements,
+    pub completed_tasks: Mapping<AccountId,u32>,
+    pub completed_high_priority: Mapping<AccountId,u32>,
+    pub member_profile: Mapping<AccountId,MemberProfile>,
+    pub task_project: Mapping<TaskId,ProjectId>,
+    pub task_awarded_points: Mapping<TaskId,u32>,
+    pub project_points: Mapping<(ProjectId,AccountId),u32>,
+    pub project_contributors: Mapping<ProjectId,Vec<AccountId>>,
+    pub point_awards: Mapping<AwardId,PointAward>,
+    pub award_id: u32,
+    pub kudos_spent: Mapping<(AccountId,u64),u32>,
+    pub kudos_config: KudosConfig,
+    pub give_epoch: Mapping<EpochId,GiveEpoch>,
+    pub epoch_allocation: Mapping<(EpochId,AccountId,AccountId),u32>,
+    pub epoch_given: Mapping<(EpochId,AccountId),u32>,
+    pub epoch_received: Mapping<(EpochId,AccountId),u32>,
+    pub current_epoch: Option<EpochId>,
+    pub epoch_id: u32,
+    pub lifetime_points: Mapping<AccountId,u32>,
+    pub points_updated: Mapping<AccountId,Timestamp>,
+    pub decay_model: DecayModel,
+    pub season_points: Mapping<(SeasonId,AccountId),u32>,
+    pub season_snapshot: Mapping<SeasonId,Vec<(AccountId,u32)>>,
+    pub seasons: Mapping<SeasonId,Season>,
+    pub season_start: Timestamp,
+    pub season_length: Timestamp,
+    pub season_id: u32,
+    pub member_badges: Mapping<AccountId,Vec<(BadgeId,TokenId)>>,
+    pub badge_rules: Mapping<BadgeId,BadgeRule>,
+    pub badge_rule_ids: Vec<BadgeId>,
+    pub badge_contract: Option<AccountId>,
+    pub badge_id: u32,
+    pub badge_serial: u32,
+    pub task_certificate: Mapping<TaskId,TokenId>,
+    pub certificate_contract: Option<AccountId>,
+    pub points_supply: Balance,
+    pub redemptions: Mapping<RedemptionId,Redemption>,
+    pub redemption_rewards: Mapping<(RedemptionId,AccountId),Balance>,
+    pub current_redemption: Option<RedemptionId>,
+    pub redemption_id: u32,
+    pub reserved_funds: Mapping<Asset,Balance>,
+    pub treasury_spends: Mapping<SpendId,TreasurySpend>,
+    pub spend_id: u32,
+    pub treasury_tokens: Vec<AccountId>,
+    pub streams: Mapping<StreamId,Stream>,
+    pub member_streams: Mapping<AccountId,Vec<StreamId>>,
+    pub stream_id: u32,
+    pub expulsions: Mapping<AccountId,ProposalId>,
+    pub epoch_payouts: Mapping<(EpochId,AccountId),Balance>,
+    pub min_turnout: u32,
+    /// Kind of the proposals created since proposals carried one, a missing entry is `General`
+    pub proposal_kind: Mapping<ProposalId,ProposalKind>,
+    /// Merkle allowlist accepted by `join_with_proof` next to the eligibility policy
+    pub allowlist_root: Option<Hash>,
 }
 
 impl Default for Data {
@@ -59,8 +175,8 @@ impl Default for Data {
             member_token: Default::default(),
             member_points: Default::default(),
             member_votes: Default::default(),
-            member_proposals: Default::default(),
             member_tasks: Default::default(),
+            member_proposals: Default::default(),
             project_tasks: Default::default(),
             project_members: Default::default(),
             token: ZERO_ADDRESS.into(),
@@ -69,6 +185,83 @@ impl Default for Data {
             member_id: 0,
             project_id: 0,
             task_id: 0,
+            admin_action: Default::default(),
+            signers: Default::default(),
+            threshold: 0,
+            action_id: 0,
+            applications: Default::default(),
+            voting_period: DEFAULT_VOTING_PERIOD,
+            reviewer_tasks: Default::default(),
+            member_projects: Default::default(),
+            member_nft: Default::default(),
+            token_member: Default::default(),
+            eligibility: EligibilityPolicy::Psp34(Default::default()),
+            vouches: Default::default(),
+            vouch_stakes: Default::default(),
+            vouch_admitted: Default::default(),
+            vouch_config: Default::default(),
+            member_expiry: Default::default(),
+            membership_terms: Default::default(),
+            last_activity: Default::default(),
+            inactivity_period: 0,
+            invites: Default::default(),
+            invited_by: Default::default(),
+            member_invitees: Default::default(),
+            role_members: Default::default(),
+            member_tier: Default::default(),
+            tiers: Default::default(),
+            tier_requirements: Default::default(),
+            completed_tasks: Default::default(),
+            completed_high_priority: Default::default(),
+            member_profile: Default::default(),
+            task_project: Default::default(),
+            task_awarded_points: Default::default(),
+            project_points: Default::default(),
+            project_contributors: Default::default(),
+            point_awards: Default::default(),
+            award_id: 0,
+            kudos_spent: Default::default(),
+            kudos_config: Default::default(),
+            give_epoch: Default::default(),
+            epoch_allocation: Default::default(),
+            epoch_given: Default::default(),
+            epoch_received: Default::default(),
+            current_epoch: None,
+            epoch_id: 0,
+            lifetime_points: Default::default(),
+            points_updated: Default::default(),
+            decay_model: DecayModel::None,
+            season_points: Default::default(),
+            season_snapshot: Default::default(),
+            seasons: Default::default(),
+            season_start: 0,
+            season_length: 0,
+            season_id: 1,
+            member_badges: Default::default(),
+            badge_rules: Default::default(),
+            badge_rule_ids: Default::default(),
+            badge_contract: None,
+            badge_id: 0,
+            badge_serial: 0,
+            task_certificate: Default::default(),
+            certificate_contract: None,
+            points_supply: 0,
+            redemptions: Default::default(),
+            redemption_rewards: Default::default(),
+            current_redemption: None,
+            redemption_id: 0,
+            reserved_funds: Default::default(),
+            treasury_spends: Default::default(),
+            spend_id: 0,
+            treasury_tokens: Default::default(),
+            streams: Default::default(),
+            member_streams: Default::default(),
+            stream_id: 0,
+            expulsions: Default::default(),
+            epoch_payouts: Default::default(),
+            min_turnout: 1,
+            proposal_kind: Default::default(),
+            allowlist_root: None,
         }
     }
 }
@@ -108,6 +301,164 @@ pub enum DaoError {
      QuorumNotAchieved,
      /// Not A Project Member
      NotAProjectMember,
+     /// Caller is not a multisig signer
+     NotASigner,
+     /// Signer Already Exists
+     SignerAlreadyExists,
+     /// Signer Does Not Exist
+     SignerDoesNotExist,
+     /// Threshold must be between 1 and the number of signers
+     InvalidThreshold,
+     /// Admin Action Does Not Exist
+     ActionDoesNotExist,
+     /// Admin Action is no longer pending
+     ActionNotPending,
+     /// Signer has already confirmed the action
+     ActionAlreadyConfirmed,
+     /// Signer has not confirmed the action
+     ActionNotConfirmed,
+     /// Not enough confirmations to execute the action
+     NotEnoughConfirmations,
+     /// Membership Application Already Exists
+     ApplicationAlreadyExists,
+     /// Proposal kind cannot be created with this message
+     InvalidProposalKind,
+     /// Token is already bound to another member
+     TokenAlreadyBound,
+     /// Member still owns the token bound to the membership
+     MembershipStillValid,
+     /// No Merkle allowlist has been set
+     AllowlistNotSet,
+     /// Vouching is not enabled
+     VouchingDisabled,
+     /// Member does not have enough points
+     InsufficientPoints,
+     /// Member has already vouched for the candidate
+     AlreadyVouched,
+     /// Vouch Does Not Exist
+     VouchDoesNotExist,
+     /// Stake is locked until the lock period has passed
+     VouchLocked,
+     /// Membership has expired and must be renewed
+     MembershipExpired,
+     /// Transferred value is below the renewal fee
+     InsufficientFee,
+     /// Memberships do not expire in this DAO
+     RenewalNotAvailable,
+     /// Quorum is a percentage and cannot exceed 100
+     InvalidQuorum,
+     /// Invite Already Exists
+     InviteAlreadyExists,
+     /// Invite Does Not Exist
+     InviteDoesNotExist,
+     /// Invite needs a future expiry
+     InvalidInvite,
+     /// Invite has expired
+     InviteExpired,
+     /// Invite has no uses left
+     InviteExhausted,
+     /// Caller does not have the role required by the message
+     MissingRole,
+     /// Role is not one of the DAO roles
+     InvalidRole,
+     /// Account already has the role
+     RoleAlreadyGranted,
+     /// Account does not have the role
+     RoleNotGranted,
+     /// Tier thresholds must be strictly increasing
+     InvalidTiers,
+     /// Member's tier is too low for this action
+     TierTooLow,
+     /// Profile has too many skills or a field is too long
+     InvalidProfile,
+     /// Member has not set a profile
+     ProfileDoesNotExist,
+     /// Kudos are not enabled
+     KudosDisabled,
+     /// Member has not enough kudos left in this epoch
+     KudosAllowanceExceeded,
+     /// Point transfers are not enabled
+     PointTransfersDisabled,
+     /// Members cannot send points to themselves
+     SelfTransfer,
+     /// An epoch is already open
+     EpochAlreadyOpen,
+     /// No epoch is open
+     NoOpenEpoch,
+     /// Epoch needs a duration and a budget
+     InvalidEpoch,
+     /// Epoch has ended, allocations are closed
+     EpochEnded,
+     /// Epoch has not ended yet
+     EpochNotFinished,
+     /// Account is not a participant of the epoch
+     NotAParticipant,
+     /// Allocations exceed the epoch budget
+     BudgetExceeded,
+     /// Decay period must not be zero
+     InvalidDecayModel,
+     /// Season has not reached its length yet
+     SeasonNotFinished,
+     /// Achievement threshold must not be zero
+     InvalidBadgeRule,
+     /// Badge Rule Does Not Exist
+     BadgeRuleDoesNotExist,
+     /// No certificate collection has been set
+     CertificatesDisabled,
+     /// Task is not done yet
+     TaskNotDone,
+     /// Certificate has already been minted for the task
+     CertificateAlreadyMinted,
+     /// Certificate collection refused to mint
+     CertificateMintFailed,
+     /// Redemption rate, pool and length must not be zero
+     InvalidRedemption,
+     /// Redemption window is still open
+     RedemptionAlreadyOpen,
+     /// No redemption window is open
+     NoOpenRedemption,
+     /// Redemption window has not ended yet
+     RedemptionNotFinished,
+     /// Reward pool cannot cover the redemption
+     RewardPoolExceeded,
+     /// No reward to claim
+     NothingToClaim,
+     /// Reward could not be transferred
+     RewardTransferFailed,
+     /// Amount must not be zero
+     ZeroAmount,
+     /// Treasury does not hold enough funds
+     InsufficientTreasury,
+     /// Token is not on the treasury allowlist
+     TokenNotAllowed,
+     /// Token is already on the treasury allowlist
+     TokenAlreadyAllowed,
+     /// Tokens on the treasury allowlist belong to the DAO
+     TokenNotRecoverable,
+     /// Token contract refused the transfer
+     TokenTransferFailed,
+     /// Stream must have a rate and end after its start and cliff
+     InvalidStream,
+     /// Stream Does Not Exist
+     StreamDoesNotExist,
+     /// Stream has been cancelled
+     StreamNotActive,
+     /// Nothing accrued since the last withdrawal
+     NothingToWithdraw,
+     /// Task has not been submitted for review
+     TaskNotUnderReview,
+     /// Awarded points exceed the points of the task
+     TooManyPoints,
+     /// Assignee cannot review their own task
+     SelfReview,
+     /// Redemption Does Not Exist
+     RedemptionDoesNotExist,
+     /// Redemption window has already been closed
+     RedemptionAlreadyClosed,
+     /// Inviter is no longer a member
+     InviterNotMember,
+     /// Task is already done
+     TaskAlreadyDone,
 
 }
 
@@ -149,6 +500,40 @@ impl Default for Proposal {
     }
 }
 
+/// What happens when a proposal passes.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub enum ProposalKind {
+    /// Signalling proposal, nothing is executed
+    General,
+    /// Application of a non-member, the applicant is added on pass
+    Membership(AccountId),
+    /// Expulsion of a member, the member is removed on pass
+    Expulsion(AccountId),
+    /// Replaces the eligibility policy, e.g. with a new Merkle allowlist
+    SetEligibilityPolicy(EligibilityPolicy),
+    /// Grants a role to a member
+    GrantRole(RoleType,AccountId),
+    /// Revokes a role from an account
+    RevokeRole(RoleType,AccountId),
+    /// Sets or clears the PSP34 contract badges are minted from, it must refuse transfers (see `contracts/badge`)
+    SetBadgeContract(Option<AccountId>),
+    /// Adds a badge awarded for an achievement
+    AddBadgeRule(BadgeRule),
+    /// Stops awarding a badge
+    RemoveBadgeRule(BadgeId),
+    /// Opens a window to redeem points for rewards
+    OpenRedemption(RedemptionTerms),
+    /// Pays an asset of the treasury to a beneficiary
+    TreasurySpend(Asset,AccountId,Balance),
+    /// Streams an asset of the treasury to a member
+    CreateStream(StreamTerms),
+    /// Stops a stream and pays what it accrued
+    CancelStream(StreamId),
+    /// Replaces the root of the Merkle allowlist used by `join_with_proof`
+    SetMerkleRoot(Option<Hash>),
+}
+
 #[derive(Encode, Decode, Debug)]
 #[cfg_attr(
     feature = "std",
@@ -182,6 +567,7 @@ pub enum VoteStatus {
     InProgress,
 	Passed,
 	Failed,
+	Vetoed,
 }
 
 #[derive(Encode, Decode, Debug)]
@@ -235,6 +621,404 @@ pub enum TaskStatus {
     Done,
 }
 
+/// Duration and renewal conditions of memberships.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct MembershipTerms {
+    /// Length of a membership period, 0 means memberships never expire
+    pub duration: Timestamp,
+    /// Native tokens to transfer with `renew_membership`
+    pub renewal_fee: Balance,
+    /// Members who joined with a token must still own it to renew
+    pub require_token: bool,
+}
+
+impl Default for MembershipTerms {
+    fn default() -> Self {
+        Self {
+            duration: 0,
+            renewal_fee: 0,
+            require_token: false,
+        }
+    }
+}
+
+/// Invite redeemable by whoever knows the secret hashed into its commitment.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Invite {
+    pub inviter: AccountId,
+    /// Only account allowed to redeem the invite, anyone with the secret if unset
+    pub invitee: Option<AccountId>,
+    pub expiry: Timestamp,
+    pub max_uses: u32,
+    pub uses: u32,
+}
+
+/// Rules for admitting new members through vouches of existing members.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct VouchConfig {
+    /// Vouches needed to admit a candidate, 0 disables vouching
+    pub required_vouches: u32,
+    /// Points a member needs to be allowed to vouch
+    pub min_points: u32,
+    /// Points staked by each voucher, slashed if the candidate is expelled
+    pub stake: u32,
+    /// Time after admission before vouchers can take their stake back
+    pub lock_period: Timestamp,
+}
+
+impl Default for VouchConfig {
+    fn default() -> Self {
+        Self {
+            required_vouches: 0,
+            min_points: 0,
+            stake: 0,
+            lock_period: 0,
+        }
+    }
+}
+
+/// Asset held by the treasury.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub enum Asset {
+    /// Native token of the chain
+    Native,
+    /// PSP22 token on the treasury allowlist
+    PSP22(AccountId),
+}
+
+/// Terms of a redemption window, voted by governance.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct RedemptionTerms {
+    pub asset: Asset,
+    /// Amount of the asset paid per redeemed point
+    pub rate: Balance,
+    /// Maximum amount paid over the window
+    pub pool: Balance,
+    pub length: Timestamp,
+}
+
+/// Window in which members redeem their points for a share of a reward pool.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Redemption {
+    pub start: Timestamp,
+    pub end: Timestamp,
+    pub asset: Asset,
+    pub rate: Balance,
+    pub pool: Balance,
+    pub points_redeemed: u32,
+    /// Amount owed to the members who redeemed
+    pub committed: Balance,
+    /// The part of the pool nobody redeemed has been released to the treasury
+    pub closed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub enum SpendStatus {
+    Paid,
+    /// The transfer failed when the proposal was executed
+    Failed,
+}
+
+/// Payment made from the treasury by a passed proposal.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct TreasurySpend {
+    pub proposal_id: ProposalId,
+    pub asset: Asset,
+    pub beneficiary: AccountId,
+    pub amount: Balance,
+    pub timestamp: Timestamp,
+    pub status: SpendStatus,
+}
+
+/// Terms of a payment stream, voted by governance.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct StreamTerms {
+    pub beneficiary: AccountId,
+    pub asset: Asset,
+    /// Amount accrued per millisecond
+    pub rate: Balance,
+    pub start: Timestamp,
+    pub end: Timestamp,
+    /// Nothing can be withdrawn before the cliff
+    pub cliff: Timestamp,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub enum StreamStatus {
+    Active,
+    Cancelled,
+}
+
+/// Payment accruing from the treasury to a member between `start` and `end`.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Stream {
+    pub beneficiary: AccountId,
+    pub asset: Asset,
+    pub rate: Balance,
+    pub start: Timestamp,
+    /// Moved to the cancellation time when the stream is cancelled
+    pub end: Timestamp,
+    pub cliff: Timestamp,
+    pub withdrawn: Balance,
+    pub status: StreamStatus,
+}
+
+/// Milestone a member must reach to earn a badge.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub enum Achievement {
+    /// Joining the DAO
+    Joined,
+    /// Number of own tasks reviewed
+    TasksCompleted(u32),
+    /// Number of own high priority tasks reviewed
+    HighPriorityTasksCompleted(u32),
+    /// Points earned over the whole membership
+    LifetimePoints(u32),
+}
+
+/// Soulbound badge minted once to every member reaching `achievement`.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct BadgeRule {
+    pub name: String,
+    pub achievement: Achievement,
+}
+
+/// Closed season of points accounting.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Season {
+    pub start: Timestamp,
+    pub end: Timestamp,
+}
+
+/// How the points of members fade over time, counted in full periods since their last change.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub enum DecayModel {
+    /// Points never decay
+    None,
+    /// Points are halved every period
+    HalfLife(Timestamp),
+    /// Points lose `amount` every period
+    Linear { period: Timestamp, amount: u32 },
+}
+
+/// Points awarded to a member, kept in chronological order for windowed rankings.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct PointAward {
+    pub member: AccountId,
+    pub project: Option<ProjectId>,
+    pub points: u32,
+    pub timestamp: Timestamp,
+}
+
+impl Default for PointAward {
+    fn default() -> Self {
+        Self {
+            member: ZERO_ADDRESS.into(),
+            project: None,
+            points: 0,
+            timestamp: 0,
+        }
+    }
+}
+
+/// Public profile set by a member.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct MemberProfile {
+    pub display_name: String,
+    pub avatar_uri: String,
+    pub skills: Vec<String>,
+    /// Hash of the contact details, shared off-chain
+    pub contact_hash: Hash,
+}
+
+/// What the allocations of an epoch turn into when it closes.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub enum EpochReward {
+    /// Each allocated token becomes a point of the recipient
+    Points,
+    /// Allocations are shares of `amount` of an asset of the treasury, claimed once the epoch is closed
+    PayoutShare(Asset,Balance),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub enum EpochStatus {
+    Open,
+    Closed,
+}
+
+/// Round in which active members distribute a budget of allocation tokens among each other.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct GiveEpoch {
+    pub start: Timestamp,
+    pub end: Timestamp,
+    /// Tokens each participant can allocate
+    pub budget: u32,
+    pub reward: EpochReward,
+    /// Members active when the epoch started
+    pub participants: Vec<AccountId>,
+    pub total_allocated: u32,
+    pub status: EpochStatus,
+}
+
+/// Allowance of points members can gift to each other.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct KudosConfig {
+    /// Points each member can give per epoch
+    pub allowance: u32,
+    /// Length of a kudos epoch, 0 disables kudos
+    pub epoch_length: Timestamp,
+    /// Members can transfer the points they earned
+    pub transfers_enabled: bool,
+}
+
+impl Default for KudosConfig {
+    fn default() -> Self {
+        Self {
+            allowance: 0,
+            epoch_length: 0,
+            transfers_enabled: false,
+        }
+    }
+}
+
+/// Level of progression reached by members with at least `min_points`.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Tier {
+    pub name: String,
+    pub min_points: u32,
+}
+
+/// Tier levels (1 for the first tier, 0 for none) needed for gated actions.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct TierRequirements {
+    /// Level needed to create proposals
+    pub create_proposal: u32,
+    /// Level needed to review high priority tasks
+    pub review_high_priority: u32,
+}
+
+impl Default for TierRequirements {
+    fn default() -> Self {
+        Self {
+            create_proposal: 0,
+            review_high_priority: 0,
+        }
+    }
+}
+
+/// Privileged DAO action that must be confirmed by `threshold` signers before it is executed.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub enum AdminAction {
+    AddMember(AccountId),
+    SetQuorum(u32),
+    VetoProposal(ProposalId),
+    AddSigner(AccountId),
+    RemoveSigner(AccountId),
+    ChangeThreshold(u32),
+    SetVotingPeriod(Timestamp),
+    SetEligibilityPolicy(EligibilityPolicy),
+    GrantRole(RoleType,AccountId),
+    RevokeRole(RoleType,AccountId),
+    SetMinTurnout(u32),
+}
+
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub enum ActionStatus {
+    Pending,
+    Executed,
+}
+
+#[derive(Encode, Decode, Debug)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct AdminProposal {
+    pub proposer: AccountId,
+    pub action: AdminAction,
+    pub confirmations: Vec<AccountId>,
+    pub status: ActionStatus,
+}
+
+impl Data {
+    pub fn has_role(&self, role: RoleType, account: AccountId) -> bool {
+        self.role_members.get(&role).unwrap_or_default().contains(&account)
+    }
+}
+
 impl From<OwnableError> for DaoError {
     fn from(error: OwnableError) -> Self {
         DaoError::OwnableError(error)
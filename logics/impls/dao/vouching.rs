@@ -0,0 +1,184 @@
+use crate::{
+    impls::dao::types::{
+        Data,
+        DaoError,
+        VouchConfig,
+        VoteStatus,
+        ADMIN,
+    },
+    impls::dao::decay::DecayInternal,
+    impls::dao::points_token::PointsInternal,
+    impls::dao::roles::only_role,
+    impls::dao::tiers::TiersInternal,
+    impls::dao::dao::{
+        DaoEvents,
+        Internal,
+    },
+    traits::vouching::DaoVouching,
+};
+use ink::prelude::vec::Vec;
+
+use openbrush::{
+    modifiers,
+    traits::{
+        AccountId,
+        DefaultEnv,
+        Storage,
+    },
+};
+
+pub trait VouchingInternal {
+    /// Returns the stakes of all the vouchers of `candidate`, or burns them when `slash` is set.
+    fn settle_vouches(&mut self,candidate: AccountId,slash: bool);
+
+    /// Returns the stakes of a member who leaves, unless they are still locked or an
+    /// expulsion against the member is being voted, in which case they stay at risk.
+    fn release_vouches(&mut self,candidate: AccountId);
+
+    /// Whether the stakes on `candidate` are still within the lock period or await
+    /// the outcome of an expulsion.
+    fn vouches_locked(&self,candidate: AccountId) -> bool;
+}
+
+impl<T> DaoVouching for T
+where
+    T: Storage<Data>,
+{
+    default fn vouch(&mut self, candidate: AccountId) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        if !self.data::<Data>().members.contains(&caller) {
+            return Err(DaoError::MemberDoesNotExist)
+        }
+
+        if self.data::<Data>().members.contains(&candidate) {
+            return Err(DaoError::MemberAlreadyExists)
+        }
+
+        let config = self.data::<Data>().vouch_config.clone();
+
+        if config.required_vouches == 0 {
+            return Err(DaoError::VouchingDisabled)
+        }
+
+        if self.data::<Data>().vouch_stakes.get(&(caller.clone(),candidate.clone())).is_some() {
+            return Err(DaoError::AlreadyVouched)
+        }
+
+        self.apply_decay(caller.clone());
+
+        let points = self.data::<Data>().member_points.get(&caller).unwrap_or(0);
+
+        if points < config.min_points || points < config.stake {
+            return Err(DaoError::InsufficientPoints)
+        }
+
+        // Put the stake aside until the candidate has proven themselves
+
+        self.burn_points(caller.clone(),config.stake)?;
+        self.data::<Data>().vouch_stakes.insert(&(caller.clone(),candidate.clone()),&config.stake);
+        self.refresh_tier(caller.clone());
+
+        let mut vouchers = self.data::<Data>().vouches.get(&candidate).unwrap_or_default();
+        vouchers.push(caller.clone());
+        self.data::<Data>().vouches.insert(&candidate,&vouchers);
+
+        self.emit_vouch_cast_event(caller,candidate.clone());
+
+        self.record_activity(caller.clone());
+
+        if vouchers.len() as u32 >= config.required_vouches {
+            let now = Self::env().block_timestamp();
+            let member_id = self.add_member_internal(candidate.clone());
+            self.data::<Data>().vouch_admitted.insert(&candidate,&now);
+            self.emit_member_added_event(candidate,member_id);
+        }
+
+        Ok(())
+    }
+
+    default fn withdraw_vouch(&mut self, candidate: AccountId) -> Result<(),DaoError> {
+        let caller = Self::env().caller();
+
+        let stake = match self.data::<Data>().vouch_stakes.get(&(caller.clone(),candidate.clone())) {
+            Some(stake) => stake,
+            None => return Err(DaoError::VouchDoesNotExist),
+        };
+
+        // Once the candidate is in, the stake stays at risk for the lock period, even if they leave
+
+        if self.vouches_locked(candidate.clone()) {
+            return Err(DaoError::VouchLocked)
+        }
+
+        self.mint_points(caller.clone(),stake);
+        self.data::<Data>().vouch_stakes.remove(&(caller.clone(),candidate.clone()));
+        self.refresh_tier(caller.clone());
+
+        let mut vouchers = self.data::<Data>().vouches.get(&candidate).unwrap_or_default();
+        vouchers.retain(|voucher| *voucher != caller);
+        self.data::<Data>().vouches.insert(&candidate,&vouchers);
+
+        Ok(())
+    }
+
+    #[modifiers(only_role(ADMIN))]
+    default fn set_vouch_config(&mut self, config: VouchConfig) -> Result<(),DaoError> {
+        self.data::<Data>().vouch_config = config;
+        Ok(())
+    }
+
+    default fn get_vouch_config(&self) -> VouchConfig {
+        self.data::<Data>().vouch_config.clone()
+    }
+
+    default fn get_vouchers(&self, candidate: AccountId) -> Vec<AccountId> {
+        self.data::<Data>().vouches.get(&candidate).unwrap_or_default()
+    }
+
+    default fn get_vouch_stake(&self, voucher: AccountId, candidate: AccountId) -> u32 {
+        self.data::<Data>().vouch_stakes.get(&(voucher,candidate)).unwrap_or(0)
+    }
+}
+
+impl<T> VouchingInternal for T
+where
+    T: Storage<Data>,
+{
+    default fn settle_vouches(&mut self,candidate: AccountId,slash: bool) {
+        let vouchers = self.data::<Data>().vouches.get(&candidate).unwrap_or_default();
+
+        for voucher in vouchers.into_iter() {
+            let stake = self.data::<Data>().vouch_stakes.get(&(voucher.clone(),candidate.clone())).unwrap_or(0);
+            self.data::<Data>().vouch_stakes.remove(&(voucher.clone(),candidate.clone()));
+
+            if !slash {
+                self.mint_points(voucher.clone(),stake);
+                self.refresh_tier(voucher.clone());
+            }
+        }
+
+        self.data::<Data>().vouches.remove(&candidate);
+        self.data::<Data>().vouch_admitted.remove(&candidate);
+    }
+
+    default fn release_vouches(&mut self,candidate: AccountId) {
+        if !self.vouches_locked(candidate.clone()) {
+            self.settle_vouches(candidate,false);
+        }
+    }
+
+    default fn vouches_locked(&self,candidate: AccountId) -> bool {
+        if let Some(proposal_id) = self.data::<Data>().expulsions.get(&candidate) {
+            let status = self.data::<Data>().vote.get(&proposal_id).map(|vote| vote.vote_status);
+            if status == Some(VoteStatus::InProgress) {
+                return true
+            }
+        }
+
+        match self.data::<Data>().vouch_admitted.get(&candidate) {
+            Some(admitted) => Self::env().block_timestamp() < admitted + self.data::<Data>().vouch_config.lock_period,
+            None => false,
+        }
+    }
+}
@@ -1,4 +1,8 @@
 use crate::{
+    impls::eligibility::types::{
+        Credential,
+        EligibilityPolicy,
+    },
     impls::daomanager::types::{
         Data,
         DaoId,
@@ -11,7 +15,6 @@ use ink::prelude::vec::Vec;
 use openbrush::{
     contracts::{
         ownable::*,
-        psp34::*,
         reentrancy_guard::*,
     },
     modifiers,
@@ -28,7 +31,7 @@ pub trait Internal {
 
     fn check_token(&self, token: AccountId) -> bool;
 
-    fn check_eligible(&self,account: AccountId) -> bool;
+    fn check_eligible(&self,account: AccountId,credential: &Credential) -> bool;
 
     fn dao_exists(&self, dao: AccountId) -> bool;
 }
@@ -43,6 +46,16 @@ where
         Ok(())
     }
 
+    #[modifiers(only_owner)]
+    default fn set_eligibility_policy(&mut self, policy: EligibilityPolicy) -> Result<(),DaoManagerError> {
+        self.data::<Data>().eligibility = policy;
+        Ok(())
+    }
+
+    default fn get_eligibility_policy(&self) -> EligibilityPolicy {
+        self.data::<Data>().eligibility.clone()
+    }
+
     default fn get_number_of_daos(&self) -> DaoId {
         self.data::<Data>().dao_id
     }
@@ -74,9 +87,13 @@ where
     }
 
     default fn register(&mut self) -> Result<(),DaoManagerError> {
+        self.register_with_credential(Credential::None)
+    }
+
+    default fn register_with_credential(&mut self, credential: Credential) -> Result<(),DaoManagerError> {
         let caller = Self::env().caller();
 
-        if !self.check_eligible(caller.clone()) {
+        if !self.check_eligible(caller.clone(),&credential) {
             return Err(DaoManagerError::NotEligible)
         }
 
@@ -133,15 +150,8 @@ where
         }
     }
 
-    default fn check_eligible(&self,account: AccountId) -> bool {
-        let address = self.data::<Data>().token;
-        let balance = PSP34Ref::balance_of(&address, account.clone());
-
-        if balance > 0 {
-            return true;
-        } else {
-            return false;
-        }
+    default fn check_eligible(&self,account: AccountId,credential: &Credential) -> bool {
+        self.data::<Data>().eligibility.is_satisfied(account,credential)
     }
 
     default fn dao_exists(&self, dao: AccountId) -> bool {
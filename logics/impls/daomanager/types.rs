@@ -1,3 +1,4 @@
+use crate::impls::eligibility::types::EligibilityPolicy;
 use ink::prelude::vec::Vec;
 use openbrush::{
     contracts::{
@@ -15,6 +16,7 @@ pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 pub type DaoId = u32;
 pub type MemberId = u32;
 
+/// Fields are only ever appended, so that upgraded contracts keep reading the existing storage.
 #[derive(Debug)]
 #[openbrush::upgradeable_storage(STORAGE_KEY)]
 pub struct Data {
@@ -23,6 +25,7 @@ pub struct Data {
     pub members: Vec<AccountId>,
     pub dao_id: DaoId,
     pub member_id: MemberId,
+    pub eligibility: EligibilityPolicy,
 }
 
 impl Default for Data {
@@ -33,6 +36,7 @@ impl Default for Data {
             members: Default::default(),
             dao_id: 0,
             member_id: 0,
+            eligibility: EligibilityPolicy::Psp34(Default::default()),
         }
     }
 }
@@ -0,0 +1,80 @@
+use crate::{
+    impls::eligibility::types::{
+        Credential,
+        EligibilityPolicy,
+    },
+    traits::daomanager::DaoManagerRef,
+};
+use ink::env::hash::Blake2x256;
+use ink::prelude::vec::Vec;
+use ink::primitives::Hash;
+use openbrush::{
+    contracts::{
+        psp22::PSP22Ref,
+        psp34::PSP34Ref,
+    },
+    traits::{
+        AccountId,
+    },
+};
+
+impl EligibilityPolicy {
+    pub fn is_satisfied(&self,account: AccountId,credential: &Credential) -> bool {
+        match self {
+            EligibilityPolicy::Psp34(collections) => {
+                match credential {
+                    Credential::Psp34 { collection, token_id } => {
+                        collections.contains(collection)
+                            && PSP34Ref::owner_of(collection, token_id.clone()) == Some(account)
+                    },
+                    Credential::None => {
+                        collections.iter().any(|collection| PSP34Ref::balance_of(collection, account.clone()) > 0)
+                    },
+                    _ => false,
+                }
+            },
+            EligibilityPolicy::Psp22 { token, min_balance } => {
+                PSP22Ref::balance_of(token, account.clone()) >= *min_balance
+            },
+            EligibilityPolicy::MerkleRoot(root) => {
+                match credential {
+                    Credential::MerkleProof(proof) => verify_merkle_proof(root.clone(),account,proof),
+                    _ => false,
+                }
+            },
+            EligibilityPolicy::DaoManager(manager) => {
+                DaoManagerRef::check_membership(manager, account.clone())
+            },
+            EligibilityPolicy::Allowlist(accounts) => {
+                accounts.contains(&account)
+            },
+        }
+    }
+}
+
+/// Checks that `account` is a leaf of the tree with `root`.
+///
+/// Leaves are the Blake2x256 hash of the SCALE encoded account and every node
+/// is the hash of its two children in ascending order.
+pub fn verify_merkle_proof(root: Hash,account: AccountId,proof: &[Hash]) -> bool {
+    let mut computed = [0u8; 32];
+    ink::env::hash_encoded::<Blake2x256, _>(&account, &mut computed);
+
+    for node in proof.iter() {
+        let mut sibling = [0u8; 32];
+        sibling.copy_from_slice(node.as_ref());
+
+        let mut input = Vec::with_capacity(64);
+        if computed <= sibling {
+            input.extend_from_slice(&computed);
+            input.extend_from_slice(&sibling);
+        } else {
+            input.extend_from_slice(&sibling);
+            input.extend_from_slice(&computed);
+        }
+
+        ink::env::hash_bytes::<Blake2x256>(&input, &mut computed);
+    }
+
+    Hash::from(computed) == root
+}
@@ -0,0 +1,2 @@
+pub mod eligibility;
+pub mod types;
\ No newline at end of file
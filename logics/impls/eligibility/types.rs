@@ -0,0 +1,42 @@
+use ink::prelude::vec::Vec;
+use ink::primitives::Hash;
+use openbrush::{
+    contracts::psp34::Id,
+    traits::{
+        AccountId,
+        Balance,
+    },
+};
+
+/// Rule an account has to satisfy to become a member.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub enum EligibilityPolicy {
+    /// Holder of a token in any of these PSP34 collections
+    Psp34(Vec<AccountId>),
+    /// Holder of at least `min_balance` of a PSP22 token
+    Psp22 {
+        token: AccountId,
+        min_balance: Balance,
+    },
+    /// Account is a leaf of the Merkle tree with this root
+    MerkleRoot(Hash),
+    /// Registered member of a DaoManager contract
+    DaoManager(AccountId),
+    /// Fixed list of accounts, checked without any cross-contract call
+    Allowlist(Vec<AccountId>),
+}
+
+/// Evidence supplied by the account when it joins.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Credential {
+    None,
+    /// Token owned by the account in one of the PSP34 collections of the policy
+    Psp34 {
+        collection: AccountId,
+        token_id: Id,
+    },
+    /// Merkle proof of the account's leaf
+    MerkleProof(Vec<Hash>),
+}
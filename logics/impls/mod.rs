@@ -1,2 +1,3 @@
 pub mod dao;
-pub mod daomanager;
\ No newline at end of file
+pub mod daomanager;
+pub mod eligibility;
\ No newline at end of file
@@ -0,0 +1,36 @@
+use crate::impls::dao::types::{
+    BadgeId,
+    BadgeRule,
+    TokenId,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    traits::{
+        AccountId,
+    },
+};
+
+#[openbrush::wrapper]
+pub type DaoBadgesRef = dyn DaoBadges;
+
+#[openbrush::trait_definition]
+pub trait DaoBadges {
+
+    #[ink(message)]
+    fn claim_badges(&mut self, member: AccountId) -> Vec<BadgeId>;
+
+    #[ink(message)]
+    fn get_badge_contract(&self) -> Option<AccountId>;
+
+    #[ink(message)]
+    fn get_badge_rule(&self, badge_id: BadgeId) -> Option<BadgeRule>;
+
+    #[ink(message)]
+    fn get_badge_rule_ids(&self) -> Vec<BadgeId>;
+
+    #[ink(message)]
+    fn get_member_badges(&self, member: AccountId) -> Vec<(BadgeId,TokenId)>;
+
+    #[ink(message)]
+    fn get_completed_tasks(&self, member: AccountId) -> (u32,u32);
+}
@@ -0,0 +1,29 @@
+use crate::impls::dao::types::{
+    DaoError,
+    TaskId,
+    TokenId,
+};
+use openbrush::{
+    traits::{
+        AccountId,
+    },
+};
+
+#[openbrush::wrapper]
+pub type DaoCertificatesRef = dyn DaoCertificates;
+
+#[openbrush::trait_definition]
+pub trait DaoCertificates {
+
+    #[ink(message)]
+    fn set_certificate_contract(&mut self, contract: Option<AccountId>) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn get_certificate_contract(&self) -> Option<AccountId>;
+
+    #[ink(message)]
+    fn claim_certificate(&mut self, task_id: TaskId) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn get_task_certificate(&self, task_id: TaskId) -> Option<TokenId>;
+}
@@ -1,14 +1,21 @@
+use crate::impls::eligibility::types::{
+    Credential,
+    EligibilityPolicy,
+};
 use crate::impls::dao::types::{
     DaoError,
     Project,
     Proposal,
+    ProposalKind,
     Vote,
     ProjectId,
     ProposalId,
     TaskId,
     Task,
+    TokenId,
 };
 use ink::prelude::vec::Vec;
+use ink::primitives::Hash;
 use openbrush::{
     traits::{
         AccountId,
@@ -27,11 +34,32 @@ pub trait ToyotaDao {
     fn add_member(&mut self,address: AccountId) -> Result<(),DaoError>;
 
     #[ink(message)]
-    fn join_dao(&mut self) -> Result<(),DaoError>;
+    fn join_dao(&mut self, credential: Credential) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn join_with_proof(&mut self, proof: Vec<Hash>) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn set_merkle_root(&mut self, root: Option<Hash>) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn revalidate_member(&mut self,account: AccountId) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn leave_dao(&mut self) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn remove_member(&mut self,address: AccountId) -> Result<(),DaoError>;
 
     #[ink(message)]
     fn create_proposal(&mut self,description: String, duration: Timestamp) -> Result<(),DaoError>;
 
+    #[ink(message)]
+    fn create_governance_proposal(&mut self,description: String, duration: Timestamp, kind: ProposalKind) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn apply_for_membership(&mut self, statement: String) -> Result<(),DaoError>;
+
     #[ink(message)]
     fn create_project(&mut self, description: String) -> Result<(),DaoError>;
 
@@ -60,12 +88,18 @@ pub trait ToyotaDao {
     #[ink(message)]
     fn review_task(&mut self, task_id: TaskId, review: String, awarded_points: u32) -> Result<(),DaoError>;
 
+    #[ink(message)]
+    fn reassign_task(&mut self, task_id: TaskId, assignee: AccountId, reviewer: AccountId) -> Result<(),DaoError>;
+
     #[ink(message)]
     fn get_token_address(&self) -> AccountId;
 
     #[ink(message)]
     fn get_quorum(&self) -> u32;
 
+    #[ink(message)]
+    fn get_min_turnout(&self) -> u32;
+
     #[ink(message)]
     fn get_members(&self) -> Vec<AccountId>;
 
@@ -102,6 +136,9 @@ pub trait ToyotaDao {
     #[ink(message)]
     fn get_proposal(&self,proposal_id: ProposalId) -> Proposal;
 
+    #[ink(message)]
+    fn get_proposal_kind(&self,proposal_id: ProposalId) -> ProposalKind;
+
     #[ink(message)]
     fn get_number_of_project_tasks(&self,project_id: ProjectId) -> u32;
 
@@ -113,4 +150,22 @@ pub trait ToyotaDao {
 
     #[ink(message)]
     fn get_number_of_proposals(&self) -> u32;
+
+    #[ink(message)]
+    fn set_eligibility_policy(&mut self, policy: EligibilityPolicy) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn get_eligibility_policy(&self) -> EligibilityPolicy;
+
+    #[ink(message)]
+    fn get_merkle_root(&self) -> Option<Hash>;
+
+    #[ink(message)]
+    fn get_member_token(&self, member: AccountId) -> Option<(AccountId,TokenId)>;
+
+    #[ink(message)]
+    fn get_voting_period(&self) -> Timestamp;
+
+    #[ink(message)]
+    fn get_membership_application(&self, applicant: AccountId) -> Option<ProposalId>;
 }
\ No newline at end of file
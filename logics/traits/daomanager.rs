@@ -1,3 +1,7 @@
+use crate::impls::eligibility::types::{
+    Credential,
+    EligibilityPolicy,
+};
 use crate::impls::daomanager::types::{
     DaoManagerError,
     DaoId,
@@ -21,12 +25,21 @@ pub trait DaoManager {
     #[ink(message)]
     fn set_token(&mut self, token: AccountId) -> Result<(),DaoManagerError>;
 
+    #[ink(message)]
+    fn set_eligibility_policy(&mut self, policy: EligibilityPolicy) -> Result<(),DaoManagerError>;
+
+    #[ink(message)]
+    fn get_eligibility_policy(&self) -> EligibilityPolicy;
+
     #[ink(message)]
     fn add_dao(&mut self, dao: AccountId) -> Result<(),DaoManagerError>;
 
     #[ink(message)]
     fn register(&mut self) -> Result<(),DaoManagerError>;
 
+    #[ink(message)]
+    fn register_with_credential(&mut self, credential: Credential) -> Result<(),DaoManagerError>;
+
     #[ink(message)]
     fn get_token(&self) -> AccountId;
 
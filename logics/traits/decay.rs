@@ -0,0 +1,25 @@
+use crate::impls::dao::types::{
+    DaoError,
+    DecayModel,
+};
+use openbrush::{
+    traits::{
+        AccountId,
+    },
+};
+
+#[openbrush::wrapper]
+pub type DaoDecayRef = dyn DaoDecay;
+
+#[openbrush::trait_definition]
+pub trait DaoDecay {
+
+    #[ink(message)]
+    fn set_decay_model(&mut self, model: DecayModel) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn get_decay_model(&self) -> DecayModel;
+
+    #[ink(message)]
+    fn get_lifetime_points(&self, member: AccountId) -> u32;
+}
@@ -0,0 +1,57 @@
+use crate::impls::dao::types::{
+    DaoError,
+    EpochId,
+    EpochReward,
+    GiveEpoch,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    traits::{
+        AccountId,
+        Balance,
+        Timestamp,
+    },
+};
+
+#[openbrush::wrapper]
+pub type DaoEpochsRef = dyn DaoEpochs;
+
+#[openbrush::trait_definition]
+pub trait DaoEpochs {
+
+    #[ink(message)]
+    fn start_epoch(&mut self, duration: Timestamp, budget: u32, reward: EpochReward) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn allocate(&mut self, recipient: AccountId, amount: u32) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn close_epoch(&mut self) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn claim_epoch_payout(&mut self, epoch_id: EpochId) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn get_current_epoch(&self) -> Option<EpochId>;
+
+    #[ink(message)]
+    fn get_epoch(&self, epoch_id: EpochId) -> Option<GiveEpoch>;
+
+    #[ink(message)]
+    fn get_number_of_epochs(&self) -> u32;
+
+    #[ink(message)]
+    fn get_allocation(&self, epoch_id: EpochId, giver: AccountId, recipient: AccountId) -> u32;
+
+    #[ink(message)]
+    fn get_epoch_budget_left(&self, epoch_id: EpochId, member: AccountId) -> u32;
+
+    #[ink(message)]
+    fn get_epoch_received(&self, epoch_id: EpochId, member: AccountId) -> u32;
+
+    #[ink(message)]
+    fn get_epoch_results(&self, epoch_id: EpochId) -> Vec<(AccountId,u32)>;
+
+    #[ink(message)]
+    fn get_epoch_payout(&self, epoch_id: EpochId, member: AccountId) -> Balance;
+}
@@ -0,0 +1,37 @@
+use crate::impls::dao::types::{
+    DaoError,
+    Invite,
+};
+use ink::prelude::vec::Vec;
+use ink::primitives::Hash;
+use openbrush::{
+    traits::{
+        AccountId,
+        Timestamp,
+    },
+};
+
+#[openbrush::wrapper]
+pub type DaoInviteRef = dyn DaoInvite;
+
+#[openbrush::trait_definition]
+pub trait DaoInvite {
+
+    #[ink(message)]
+    fn create_invite(&mut self, commitment: Hash, invitee: Option<AccountId>, expiry: Timestamp, max_uses: u32) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn revoke_invite(&mut self, commitment: Hash) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn join_with_invite(&mut self, secret: Vec<u8>) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn get_invite(&self, commitment: Hash) -> Option<Invite>;
+
+    #[ink(message)]
+    fn get_inviter(&self, member: AccountId) -> Option<AccountId>;
+
+    #[ink(message)]
+    fn get_invitees(&self, member: AccountId) -> Vec<AccountId>;
+}
\ No newline at end of file
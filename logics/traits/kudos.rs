@@ -0,0 +1,32 @@
+use crate::impls::dao::types::{
+    DaoError,
+    KudosConfig,
+};
+use ink::primitives::Hash;
+use openbrush::{
+    traits::{
+        AccountId,
+    },
+};
+
+#[openbrush::wrapper]
+pub type DaoKudosRef = dyn DaoKudos;
+
+#[openbrush::trait_definition]
+pub trait DaoKudos {
+
+    #[ink(message)]
+    fn give_kudos(&mut self, recipient: AccountId, points: u32, message_hash: Hash) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn transfer_points(&mut self, recipient: AccountId, points: u32) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn set_kudos_config(&mut self, config: KudosConfig) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn get_kudos_config(&self) -> KudosConfig;
+
+    #[ink(message)]
+    fn get_kudos_left(&self, member: AccountId) -> u32;
+}
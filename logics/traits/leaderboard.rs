@@ -0,0 +1,30 @@
+use crate::impls::dao::types::ProjectId;
+use ink::prelude::vec::Vec;
+use openbrush::{
+    traits::{
+        AccountId,
+        Timestamp,
+    },
+};
+
+#[openbrush::wrapper]
+pub type DaoLeaderboardRef = dyn DaoLeaderboard;
+
+#[openbrush::trait_definition]
+pub trait DaoLeaderboard {
+
+    #[ink(message)]
+    fn get_leaderboard(&self, offset: u32, limit: u32) -> Vec<(AccountId,u32)>;
+
+    #[ink(message)]
+    fn get_member_rank(&self, member: AccountId) -> Option<u32>;
+
+    #[ink(message)]
+    fn get_project_leaderboard(&self, project_id: ProjectId, offset: u32, limit: u32) -> Vec<(AccountId,u32)>;
+
+    #[ink(message)]
+    fn get_project_points(&self, project_id: ProjectId, member: AccountId) -> u32;
+
+    #[ink(message)]
+    fn get_leaderboard_between(&self, from: Timestamp, to: Timestamp, offset: u32, limit: u32) -> Vec<(AccountId,u32)>;
+}
@@ -0,0 +1,51 @@
+use crate::impls::dao::types::{
+    DaoError,
+    MembershipTerms,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    traits::{
+        AccountId,
+        Timestamp,
+    },
+};
+
+#[openbrush::wrapper]
+pub type DaoMembershipRef = dyn DaoMembership;
+
+#[openbrush::trait_definition]
+pub trait DaoMembership {
+
+    #[ink(message, payable)]
+    fn renew_membership(&mut self) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn set_membership_terms(&mut self, terms: MembershipTerms) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn set_member_expiry(&mut self, member: AccountId, expiry: Option<Timestamp>) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn set_inactivity_period(&mut self, period: Timestamp) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn get_inactivity_period(&self) -> Timestamp;
+
+    #[ink(message)]
+    fn get_last_activity(&self, member: AccountId) -> Timestamp;
+
+    #[ink(message)]
+    fn is_member_active(&self, member: AccountId) -> bool;
+
+    #[ink(message)]
+    fn get_number_of_active_members(&self) -> u32;
+
+    #[ink(message)]
+    fn get_membership_terms(&self) -> MembershipTerms;
+
+    #[ink(message)]
+    fn get_member_expiry(&self, member: AccountId) -> Option<Timestamp>;
+
+    #[ink(message)]
+    fn get_members_expiring_before(&self, timestamp: Timestamp) -> Vec<AccountId>;
+}
\ No newline at end of file
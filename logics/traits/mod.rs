@@ -1,2 +1,21 @@
+pub mod badges;
+pub mod certificates;
 pub mod dao;
-pub mod daomanager;
\ No newline at end of file
+pub mod daomanager;
+pub mod decay;
+pub mod epochs;
+pub mod invite;
+pub mod kudos;
+pub mod leaderboard;
+pub mod membership;
+pub mod multisig;
+pub mod points_token;
+pub mod profile;
+pub mod psp34_certificate;
+pub mod rewards;
+pub mod roles;
+pub mod seasons;
+pub mod streams;
+pub mod tiers;
+pub mod treasury;
+pub mod vouching;
\ No newline at end of file
@@ -0,0 +1,43 @@
+use crate::impls::dao::types::{
+    DaoError,
+    ActionId,
+    AdminAction,
+    AdminProposal,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    traits::{
+        AccountId,
+    },
+};
+
+#[openbrush::wrapper]
+pub type DaoMultisigRef = dyn DaoMultisig;
+
+#[openbrush::trait_definition]
+pub trait DaoMultisig {
+
+    #[ink(message)]
+    fn submit_action(&mut self, action: AdminAction) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn confirm_action(&mut self, action_id: ActionId) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn revoke_confirmation(&mut self, action_id: ActionId) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn execute_action(&mut self, action_id: ActionId) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn get_signers(&self) -> Vec<AccountId>;
+
+    #[ink(message)]
+    fn get_threshold(&self) -> u32;
+
+    #[ink(message)]
+    fn get_admin_action(&self, action_id: ActionId) -> Option<AdminProposal>;
+
+    #[ink(message)]
+    fn get_number_of_actions(&self) -> u32;
+}
@@ -0,0 +1,25 @@
+use crate::impls::dao::types::{
+    DaoError,
+    MemberProfile,
+};
+use openbrush::{
+    traits::{
+        AccountId,
+    },
+};
+
+#[openbrush::wrapper]
+pub type DaoProfileRef = dyn DaoProfile;
+
+#[openbrush::trait_definition]
+pub trait DaoProfile {
+
+    #[ink(message)]
+    fn set_member_profile(&mut self, profile: MemberProfile) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn remove_member_profile(&mut self) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn get_member_profile(&self, member: AccountId) -> Option<MemberProfile>;
+}
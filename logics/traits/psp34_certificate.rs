@@ -0,0 +1,21 @@
+use ink::prelude::vec::Vec;
+use openbrush::{
+    contracts::psp34::{
+        Id,
+        PSP34Error,
+    },
+    traits::{
+        AccountId,
+    },
+};
+
+#[openbrush::wrapper]
+pub type PSP34CertificateRef = dyn PSP34Certificate;
+
+/// Minting entry point the certificate collection exposes to the DAO.
+#[openbrush::trait_definition]
+pub trait PSP34Certificate {
+
+    #[ink(message)]
+    fn mint_certificate(&mut self, to: AccountId, id: Id, attributes: Vec<(Vec<u8>,Vec<u8>)>) -> Result<(),PSP34Error>;
+}
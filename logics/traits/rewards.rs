@@ -0,0 +1,39 @@
+use crate::impls::dao::types::{
+    DaoError,
+    Redemption,
+    RedemptionId,
+};
+use openbrush::{
+    traits::{
+        AccountId,
+        Balance,
+    },
+};
+
+#[openbrush::wrapper]
+pub type DaoRewardsRef = dyn DaoRewards;
+
+#[openbrush::trait_definition]
+pub trait DaoRewards {
+
+    #[ink(message)]
+    fn redeem_points(&mut self, points: u32) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn claim_rewards(&mut self, redemption_id: RedemptionId) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn close_redemption(&mut self, redemption_id: RedemptionId) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn get_current_redemption(&self) -> Option<RedemptionId>;
+
+    #[ink(message)]
+    fn get_redemption(&self, redemption_id: RedemptionId) -> Option<Redemption>;
+
+    #[ink(message)]
+    fn get_number_of_redemptions(&self) -> u32;
+
+    #[ink(message)]
+    fn get_pending_reward(&self, redemption_id: RedemptionId, member: AccountId) -> Balance;
+}
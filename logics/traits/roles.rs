@@ -0,0 +1,29 @@
+use crate::impls::dao::types::{
+    DaoError,
+    RoleType,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    traits::{
+        AccountId,
+    },
+};
+
+#[openbrush::wrapper]
+pub type DaoRolesRef = dyn DaoRoles;
+
+#[openbrush::trait_definition]
+pub trait DaoRoles {
+
+    #[ink(message)]
+    fn renounce_role(&mut self, role: RoleType) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn has_role(&self, role: RoleType, account: AccountId) -> bool;
+
+    #[ink(message)]
+    fn get_role_members(&self, role: RoleType) -> Vec<AccountId>;
+
+    #[ink(message)]
+    fn get_roles(&self, account: AccountId) -> Vec<RoleType>;
+}
@@ -0,0 +1,40 @@
+use crate::impls::dao::types::{
+    DaoError,
+    Season,
+    SeasonId,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    traits::{
+        AccountId,
+        Timestamp,
+    },
+};
+
+#[openbrush::wrapper]
+pub type DaoSeasonsRef = dyn DaoSeasons;
+
+#[openbrush::trait_definition]
+pub trait DaoSeasons {
+
+    #[ink(message)]
+    fn close_season(&mut self) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn set_season_length(&mut self, length: Timestamp) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn get_season_length(&self) -> Timestamp;
+
+    #[ink(message)]
+    fn get_current_season(&self) -> (SeasonId,Timestamp);
+
+    #[ink(message)]
+    fn get_season(&self, season_id: SeasonId) -> Option<Season>;
+
+    #[ink(message)]
+    fn get_season_points(&self, season_id: SeasonId, member: AccountId) -> u32;
+
+    #[ink(message)]
+    fn get_season_snapshot(&self, season_id: SeasonId) -> Vec<(AccountId,u32)>;
+}
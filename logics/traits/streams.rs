@@ -0,0 +1,34 @@
+use crate::impls::dao::types::{
+    DaoError,
+    Stream,
+    StreamId,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    traits::{
+        AccountId,
+        Balance,
+    },
+};
+
+#[openbrush::wrapper]
+pub type DaoStreamsRef = dyn DaoStreams;
+
+#[openbrush::trait_definition]
+pub trait DaoStreams {
+
+    #[ink(message)]
+    fn withdraw_stream(&mut self, stream_id: StreamId) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn get_stream(&self, stream_id: StreamId) -> Option<Stream>;
+
+    #[ink(message)]
+    fn get_withdrawable(&self, stream_id: StreamId) -> Balance;
+
+    #[ink(message)]
+    fn get_member_streams(&self, member: AccountId) -> Vec<StreamId>;
+
+    #[ink(message)]
+    fn get_number_of_streams(&self) -> u32;
+}
@@ -0,0 +1,36 @@
+use crate::impls::dao::types::{
+    DaoError,
+    Tier,
+    TierRequirements,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    traits::{
+        AccountId,
+    },
+};
+
+#[openbrush::wrapper]
+pub type DaoTiersRef = dyn DaoTiers;
+
+#[openbrush::trait_definition]
+pub trait DaoTiers {
+
+    #[ink(message)]
+    fn set_tiers(&mut self, tiers: Vec<Tier>) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn set_tier_requirements(&mut self, requirements: TierRequirements) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn get_tiers(&self) -> Vec<Tier>;
+
+    #[ink(message)]
+    fn get_tier_requirements(&self) -> TierRequirements;
+
+    #[ink(message)]
+    fn get_member_tier_level(&self, member: AccountId) -> u32;
+
+    #[ink(message)]
+    fn get_member_tier(&self, member: AccountId) -> Option<Tier>;
+}
@@ -0,0 +1,52 @@
+use crate::impls::dao::types::{
+    DaoError,
+    SpendId,
+    TreasurySpend,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    traits::{
+        AccountId,
+        Balance,
+    },
+};
+
+#[openbrush::wrapper]
+pub type DaoTreasuryRef = dyn DaoTreasury;
+
+#[openbrush::trait_definition]
+pub trait DaoTreasury {
+
+    #[ink(message, payable)]
+    fn deposit(&mut self) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn add_treasury_token(&mut self, token: AccountId) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn remove_treasury_token(&mut self, token: AccountId) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn recover_tokens(&mut self, token: AccountId, to: AccountId, amount: Balance) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn get_treasury_tokens(&self) -> Vec<AccountId>;
+
+    #[ink(message)]
+    fn get_treasury_balance(&self) -> Balance;
+
+    #[ink(message)]
+    fn get_available_balance(&self) -> Balance;
+
+    #[ink(message)]
+    fn get_token_balance(&self, token: AccountId) -> Balance;
+
+    #[ink(message)]
+    fn get_available_token_balance(&self, token: AccountId) -> Balance;
+
+    #[ink(message)]
+    fn get_spend(&self, spend_id: SpendId) -> Option<TreasurySpend>;
+
+    #[ink(message)]
+    fn get_number_of_spends(&self) -> u32;
+}
@@ -0,0 +1,35 @@
+use crate::impls::dao::types::{
+    DaoError,
+    VouchConfig,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    traits::{
+        AccountId,
+    },
+};
+
+#[openbrush::wrapper]
+pub type DaoVouchingRef = dyn DaoVouching;
+
+#[openbrush::trait_definition]
+pub trait DaoVouching {
+
+    #[ink(message)]
+    fn vouch(&mut self, candidate: AccountId) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn withdraw_vouch(&mut self, candidate: AccountId) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn set_vouch_config(&mut self, config: VouchConfig) -> Result<(),DaoError>;
+
+    #[ink(message)]
+    fn get_vouch_config(&self) -> VouchConfig;
+
+    #[ink(message)]
+    fn get_vouchers(&self, candidate: AccountId) -> Vec<AccountId>;
+
+    #[ink(message)]
+    fn get_vouch_stake(&self, voucher: AccountId, candidate: AccountId) -> u32;
+}
\ No newline at end of file